@@ -1,7 +1,11 @@
 use anyhow::Error;
 use clap::{AppSettings, Parser, Subcommand};
 use tracing::Level;
-use wmtlib::{DependencyCheck, Questions};
+use wmtlib::{
+    add_dependency, configure_cache, display_info_result, display_workload_result, info,
+    per_question_outcomes, run_workload, score, AddOutcome, AddRequest, AddThreshold, CheckConfig,
+    CommandResult, CrateCheck, DependencyKind, Questions, ScoringConfig, THRESHOLD_FAILURE_EXIT_CODE,
+};
 
 #[derive(Subcommand, Debug)]
 enum Commands {
@@ -9,13 +13,19 @@ enum Commands {
     #[clap(setting(AppSettings::ArgRequiredElseHelp))]
     Question {
         /// Get a specific question
-        #[clap(help="Describe a specific question",
-        conflicts_with="list-questions",
-        possible_values=["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"])]
+        #[clap(
+            help = "Describe a specific question, by number or a fuzzy-matched search term",
+            conflicts_with = "list-questions"
+        )]
         number: Option<String>,
         /// Get the list of questions
         #[clap(long = "list-questions", short = 'l', help = "List the questions")]
         list_questions: bool,
+        #[clap(
+            help = "Path to a question set to use instead of the bundled one",
+            long = "questions-path"
+        )]
+        questions_path: Option<String>,
     },
 
     ///Run a check for a dependency or a list of dependencies.
@@ -29,8 +39,154 @@ enum Commands {
         dependencies: Vec<String>,
         #[clap(help="Check a specific test",
         long = "question", short='q',
-        possible_values=["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12"])]
+        possible_values=["1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15", "16", "17", "18", "19"])]
         question: Option<String>,
+        #[clap(
+            help = "Maximum number of checks to run concurrently",
+            long = "concurrency",
+            short = 'c'
+        )]
+        concurrency: Option<usize>,
+        #[clap(help = "Disable the on-disk response cache", long = "no-cache")]
+        no_cache: bool,
+        #[clap(
+            help = "Ignore cached responses and re-fetch everything",
+            long = "refresh"
+        )]
+        refresh: bool,
+        #[clap(
+            help = "Output format for the check results",
+            long = "format",
+            possible_values = ["table", "json", "markdown", "sarif"]
+        )]
+        format: Option<String>,
+        #[clap(
+            help = "Exit with a non-zero status if any check fails, for use in CI",
+            long = "ci"
+        )]
+        ci: bool,
+        #[clap(
+            help = "For a manifest path, check the entire resolved Cargo.lock dependency graph instead of just direct dependencies",
+            long = "transitive"
+        )]
+        transitive: bool,
+        #[clap(
+            help = "When checking the transitive graph, also walk [dev-dependencies]",
+            long = "include-dev-dependencies"
+        )]
+        include_dev_dependencies: bool,
+        #[clap(
+            help = "Path to a wmt.toml with scoring weights/threshold and check thresholds/enable flags",
+            long = "config"
+        )]
+        config: Option<String>,
+        #[clap(
+            help = "Path to a question set to use instead of the bundled one",
+            long = "questions-path"
+        )]
+        questions_path: Option<String>,
+    },
+
+    /// Run the question suite against every repository in a workload manifest
+    /// and print a comparative health report.
+    #[clap(setting(AppSettings::ArgRequiredElseHelp))]
+    Workload {
+        #[clap(help = "Path to a workload manifest (JSON: { name, repos, checks? })")]
+        manifest: String,
+        #[clap(
+            help = "Maximum number of checks to run concurrently",
+            long = "concurrency",
+            short = 'c'
+        )]
+        concurrency: Option<usize>,
+        #[clap(help = "Disable the on-disk response cache", long = "no-cache")]
+        no_cache: bool,
+        #[clap(
+            help = "Ignore cached responses and re-fetch everything",
+            long = "refresh"
+        )]
+        refresh: bool,
+        #[clap(
+            help = "Output format for the workload report",
+            long = "format",
+            possible_values = ["table", "json", "markdown"]
+        )]
+        format: Option<String>,
+    },
+
+    /// Run the check suite on a crate and, only if it passes, add it to a
+    /// `Cargo.toml`. Doubles as a pre-commit guard against adding
+    /// poorly-maintained crates.
+    #[clap(setting(AppSettings::ArgRequiredElseHelp))]
+    Add {
+        #[clap(help = "The crate to add, optionally as name@version")]
+        crate_name: String,
+        #[clap(
+            help = "Path to the manifest to write to",
+            long = "manifest-path",
+            default_value = "Cargo.toml"
+        )]
+        manifest_path: String,
+        #[clap(
+            help = "Which dependency table to add it to",
+            long = "table",
+            possible_values = ["dependencies", "dev-dependencies", "build-dependencies"],
+            default_value = "dependencies"
+        )]
+        table: String,
+        #[clap(
+            help = "Feature to enable, may be passed multiple times",
+            long = "feature",
+            multiple_values = true
+        )]
+        features: Vec<String>,
+        #[clap(help = "Disable default features", long = "no-default-features")]
+        no_default_features: bool,
+        #[clap(
+            help = "Accept Yellow checks instead of requiring all-Green",
+            long = "allow-yellow"
+        )]
+        allow_yellow: bool,
+        #[clap(
+            help = "Maximum number of checks to run concurrently",
+            long = "concurrency",
+            short = 'c'
+        )]
+        concurrency: Option<usize>,
+        #[clap(help = "Disable the on-disk response cache", long = "no-cache")]
+        no_cache: bool,
+        #[clap(
+            help = "Ignore cached responses and re-fetch everything",
+            long = "refresh"
+        )]
+        refresh: bool,
+    },
+
+    /// One-shot "should I depend on this?" lookup: registry metadata plus
+    /// the complete check matrix for a single crate.
+    #[clap(setting(AppSettings::ArgRequiredElseHelp))]
+    Info {
+        #[clap(help = "The crate to look up")]
+        crate_name: String,
+        #[clap(
+            help = "Maximum number of checks to run concurrently",
+            long = "concurrency",
+            short = 'c'
+        )]
+        concurrency: Option<usize>,
+        #[clap(help = "Disable the on-disk response cache", long = "no-cache")]
+        no_cache: bool,
+        #[clap(
+            help = "Ignore cached responses and re-fetch everything",
+            long = "refresh"
+        )]
+        refresh: bool,
+        #[clap(
+            help = "Output format for the report",
+            long = "format",
+            possible_values = ["table", "json", "markdown"]
+        )]
+        format: Option<String>,
     },
 }
 
@@ -79,17 +235,170 @@ pub fn run() -> Result<(), Error> {
         Commands::Check {
             dependencies,
             question,
+            concurrency,
+            no_cache,
+            refresh,
+            format,
+            ci,
+            transitive,
+            include_dev_dependencies,
+            config,
+            questions_path,
+        } => {
+            configure_cache(!*no_cache, *refresh);
+            let format = format.as_deref().unwrap_or(if json { "json" } else { "table" });
+
+            let mut check_config = config.as_deref().map(CheckConfig::from_file).transpose()?.unwrap_or_default();
+            if questions_path.is_some() {
+                check_config.questions_path = questions_path.to_owned();
+            }
+            let check_config = Some(check_config);
+            let crate_checker = CrateCheck::new(
+                dependencies.to_owned(),
+                verbose,
+                *concurrency,
+                *transitive,
+                *include_dev_dependencies,
+                check_config,
+            );
+            let results = crate_checker.run_checks(question.to_owned());
+            let exit_code = crate_checker.exit_code(&results);
+
+            let scoring_config = config.as_deref().map(ScoringConfig::from_file).transpose()?;
+            let flat_results: Vec<_> = results.iter().flatten().cloned().collect();
+            let verdict = scoring_config
+                .as_ref()
+                .map(|scoring_config| score(&flat_results, scoring_config));
+            let outcomes = scoring_config
+                .as_ref()
+                .map(|scoring_config| per_question_outcomes(&flat_results, scoring_config));
+
+            crate_checker.show_results(format, results);
+
+            if let Some(outcomes) = outcomes {
+                for (question, outcome) in outcomes {
+                    println!("Question {}: {}", question, outcome.value());
+                }
+            }
+            if let Some(verdict) = &verdict {
+                println!(
+                    "Weighted score: {:.2} ({})",
+                    verdict.score,
+                    if verdict.passed { "pass" } else { "fail" }
+                );
+            }
+
+            if *ci {
+                if let Some(verdict) = verdict {
+                    if !verdict.passed {
+                        std::process::exit(THRESHOLD_FAILURE_EXIT_CODE);
+                    }
+                }
+                if exit_code != 0 {
+                    std::process::exit(exit_code);
+                }
+            }
+        }
+
+        Commands::Workload {
+            manifest,
+            concurrency,
+            no_cache,
+            refresh,
+            format,
+        } => {
+            configure_cache(!*no_cache, *refresh);
+            let format = format.as_deref().unwrap_or(if json { "json" } else { "table" });
+
+            let report = run_workload(manifest, verbose, *concurrency)?;
+            display_workload_result(format, &report);
+        }
+
+        Commands::Add {
+            crate_name,
+            manifest_path,
+            table,
+            features,
+            no_default_features,
+            allow_yellow,
+            concurrency,
+            no_cache,
+            refresh,
         } => {
-            let dependency_checker = DependencyCheck::new(dependencies.to_owned(), verbose);
-            let results = dependency_checker.run_checks(question.to_owned());
-            dependency_checker.show_results(json, results)
+            configure_cache(!*no_cache, *refresh);
+
+            let (name, version) = match crate_name.split_once('@') {
+                Some((name, version)) => (name.to_string(), Some(version.to_string())),
+                None => (crate_name.to_string(), None),
+            };
+            let display_name = name.clone();
+            let table = match table.as_str() {
+                "dev-dependencies" => DependencyKind::Development,
+                "build-dependencies" => DependencyKind::Build,
+                _ => DependencyKind::Normal,
+            };
+            let threshold = match allow_yellow {
+                true => AddThreshold::AllowYellow,
+                false => AddThreshold::StrictGreen,
+            };
+
+            let request = AddRequest {
+                crate_name: name,
+                version,
+                manifest_path: manifest_path.to_owned(),
+                table,
+                features: features.to_owned(),
+                default_features: match no_default_features {
+                    true => Some(false),
+                    false => None,
+                },
+                threshold,
+                concurrency: *concurrency,
+            };
+
+            match add_dependency(request, verbose)? {
+                AddOutcome::Added { version } => {
+                    println!("Added {}@{} to {}", display_name, version, manifest_path);
+                }
+                AddOutcome::Rejected(results) => {
+                    let command_result = CommandResult {
+                        as_json: json,
+                        headers: vec![
+                            String::from("Crate"),
+                            String::from("Question"),
+                            String::from("Status"),
+                            String::from("Explanation"),
+                        ],
+                    };
+                    command_result.display_checks_result(vec![results]);
+                    std::process::exit(1);
+                }
+            }
+        }
+
+        Commands::Info {
+            crate_name,
+            concurrency,
+            no_cache,
+            refresh,
+            format,
+        } => {
+            configure_cache(!*no_cache, *refresh);
+            let format = format.as_deref().unwrap_or(if json { "json" } else { "table" });
+
+            let report = info(crate_name, verbose, *concurrency)?;
+            display_info_result(format, &report);
         }
 
         Commands::Question {
             number,
             list_questions,
+            questions_path,
         } => {
-            let questions = Questions { verbose };
+            let questions = Questions {
+                verbose,
+                questions_path: questions_path.to_owned(),
+            };
 
             if *list_questions {
                 let question_list = questions.list();
@@ -103,7 +412,10 @@ pub fn run() -> Result<(), Error> {
                         let question = questions.describe(value.to_string());
                         questions.show_results(json, question);
                     }
-                    false => {}
+                    false => {
+                        let matches = questions.fuzzy_describe(value, 5);
+                        questions.show_results(json, matches);
+                    }
                 },
             }
         }
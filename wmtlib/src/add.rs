@@ -0,0 +1,141 @@
+use std::fs;
+use std::path::Path;
+
+use anyhow::{anyhow, Result};
+use toml_edit::{Array, Document, InlineTable, Item, Table, Value};
+
+use crate::cargo_crate::DependencyKind;
+use crate::check::{CheckResult, CrateCheck};
+
+/// How strict `wmt add` should be before it's willing to write to the
+/// manifest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AddThreshold {
+    /// Refuse only when a check comes back Red.
+    AllowYellow,
+    /// Refuse on any Red or Yellow check.
+    StrictGreen,
+}
+
+/// What the caller asked to add.
+#[derive(Debug)]
+pub struct AddRequest {
+    pub crate_name: String,
+    pub version: Option<String>,
+    pub manifest_path: String,
+    pub table: DependencyKind,
+    pub features: Vec<String>,
+    pub default_features: Option<bool>,
+    pub threshold: AddThreshold,
+    pub concurrency: Option<usize>,
+}
+
+/// The result of attempting to add a dependency.
+pub enum AddOutcome {
+    Added { version: String },
+    Rejected(Vec<CheckResult>),
+}
+
+/// Runs the full `CrateCheck` pipeline on `request.crate_name` and, only if
+/// it clears `request.threshold`, writes it into `request.manifest_path`
+/// with `toml_edit` so the rest of the manifest's formatting and ordering
+/// is preserved. On failure, nothing is written and the offending
+/// `CheckResult`s are returned so the caller can print them.
+///
+/// `Crate::from_name` (and every check built on it) only ever looks at a
+/// crate's latest published version — there's no plumbing to run the
+/// question suite against an arbitrary historical release. So a pinned
+/// `request.version` that isn't that latest version is rejected outright
+/// rather than silently checking the latest and writing the pin anyway,
+/// which would defeat the point of gating `add` on the checks at all.
+pub fn add_dependency(request: AddRequest, verbose: bool) -> Result<AddOutcome> {
+    let crate_check = CrateCheck::new(
+        vec![request.crate_name.clone()],
+        verbose,
+        request.concurrency,
+        false,
+        false,
+        None,
+    );
+
+    let checked_version = crate_check
+        .crates
+        .first()
+        .and_then(|cargo_crate| cargo_crate.version.as_ref())
+        .and_then(|version| version.remote.clone())
+        .ok_or_else(|| anyhow!("Could not resolve a version for {}", request.crate_name))?;
+
+    if let Some(requested) = &request.version {
+        if requested != &checked_version {
+            return Err(anyhow!(
+                "wmt add only vets a crate's latest published version ({checked_version}); \
+                 {name}@{requested} would write a version the checks never ran against",
+                name = request.crate_name
+            ));
+        }
+    }
+
+    let results: Vec<CheckResult> = crate_check
+        .run_checks(None)
+        .into_iter()
+        .flatten()
+        .collect();
+
+    let fails = results.iter().any(|result| match request.threshold {
+        AddThreshold::AllowYellow => result.is_failing(),
+        AddThreshold::StrictGreen => result.is_failing() || result.is_yellow(),
+    });
+
+    if fails {
+        return Ok(AddOutcome::Rejected(results));
+    }
+
+    write_to_manifest(&request, &checked_version)?;
+    Ok(AddOutcome::Added {
+        version: checked_version,
+    })
+}
+
+fn write_to_manifest(request: &AddRequest, version: &str) -> Result<()> {
+    let manifest_path = Path::new(&request.manifest_path);
+    let contents = fs::read_to_string(manifest_path)
+        .map_err(|_| anyhow!("Could not read {}", request.manifest_path))?;
+    let mut document = contents
+        .parse::<Document>()
+        .map_err(|_| anyhow!("{} is not valid TOML", request.manifest_path))?;
+
+    let table_name = match request.table {
+        DependencyKind::Normal => "dependencies",
+        DependencyKind::Development => "dev-dependencies",
+        DependencyKind::Build => "build-dependencies",
+    };
+
+    if document[table_name].is_none() {
+        document[table_name] = Item::Table(Table::new());
+    }
+
+    let entry = if request.features.is_empty() && request.default_features.is_none() {
+        Item::Value(Value::from(version))
+    } else {
+        let mut inline = InlineTable::new();
+        inline.insert("version", Value::from(version));
+        if !request.features.is_empty() {
+            let mut array = Array::new();
+            for feature in &request.features {
+                array.push(feature.as_str());
+            }
+            inline.insert("features", Value::Array(array));
+        }
+        if let Some(default_features) = request.default_features {
+            inline.insert("default-features", Value::from(default_features));
+        }
+        Item::Value(Value::InlineTable(inline))
+    };
+
+    document[table_name][request.crate_name.as_str()] = entry;
+
+    fs::write(manifest_path, document.to_string())
+        .map_err(|_| anyhow!("Could not write {}", request.manifest_path))?;
+
+    Ok(())
+}
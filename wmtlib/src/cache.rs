@@ -0,0 +1,125 @@
+use std::path::PathBuf;
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+const CACHE_APP_DIR: &str = "wmt";
+const CACHE_TTL_SECS: u64 = 60 * 60;
+
+static CACHE_CONFIG: OnceLock<CacheConfig> = OnceLock::new();
+
+#[derive(Debug, Clone, Copy)]
+struct CacheConfig {
+    enabled: bool,
+    refresh: bool,
+}
+
+/// Configures the on-disk response cache for the rest of the process.
+/// `enabled` corresponds to `--no-cache` (pass `false` to disable entirely)
+/// and `refresh` to `--refresh` (skip reads but still write fresh entries).
+/// Only the first call takes effect; later calls are ignored.
+pub fn configure(enabled: bool, refresh: bool) {
+    let _ = CACHE_CONFIG.set(CacheConfig { enabled, refresh });
+}
+
+fn config() -> CacheConfig {
+    *CACHE_CONFIG.get_or_init(|| CacheConfig {
+        enabled: true,
+        refresh: false,
+    })
+}
+
+/// An on-disk entry: the cached value plus the `ETag` the response carried,
+/// so a later run can issue a conditional request instead of skipping the
+/// network entirely once the TTL has lapsed.
+#[derive(Debug, Serialize, serde::Deserialize)]
+struct CacheEntry<T> {
+    value: T,
+    etag: Option<String>,
+}
+
+/// Looks up a cached response, returning `None` on a miss, an expired entry,
+/// or when caching is disabled/being refreshed.
+pub fn get<T: DeserializeOwned>(namespace: &str, key: &str) -> Option<T> {
+    let config = config();
+    if !config.enabled || config.refresh {
+        return None;
+    }
+
+    let path = entry_path(namespace, key)?;
+    let modified = std::fs::metadata(&path).ok()?.modified().ok()?;
+    if modified.elapsed().unwrap_or(Duration::MAX) > Duration::from_secs(CACHE_TTL_SECS) {
+        return None;
+    }
+
+    read_entry(&path).map(|entry: CacheEntry<T>| entry.value)
+}
+
+/// Persists a response so a later run (within the TTL) can skip the network.
+pub fn set<T: Serialize>(namespace: &str, key: &str, value: &T) {
+    set_with_etag(namespace, key, value, None)
+}
+
+/// The `ETag` recorded for `key`, even if its TTL has expired, so a caller
+/// can issue a conditional (`If-None-Match`) request instead of a plain one.
+pub fn get_etag(namespace: &str, key: &str) -> Option<String> {
+    if !config().enabled {
+        return None;
+    }
+
+    let path = entry_path(namespace, key)?;
+    read_entry::<serde_json::Value>(&path).and_then(|entry| entry.etag)
+}
+
+/// Like [`get`], but ignores the TTL — used after a `304 Not Modified` to
+/// return the value that the still-valid `ETag` refers to.
+pub fn get_stale<T: DeserializeOwned>(namespace: &str, key: &str) -> Option<T> {
+    let path = entry_path(namespace, key)?;
+    read_entry(&path).map(|entry: CacheEntry<T>| entry.value)
+}
+
+/// Persists a response along with the `ETag` its response carried, if any.
+pub fn set_with_etag<T: Serialize>(namespace: &str, key: &str, value: &T, etag: Option<&str>) {
+    if !config().enabled {
+        return;
+    }
+
+    let path = match entry_path(namespace, key) {
+        Some(path) => path,
+        None => return,
+    };
+    if let Some(parent) = path.parent() {
+        let _ = std::fs::create_dir_all(parent);
+    }
+    let entry = CacheEntry {
+        value,
+        etag: etag.map(str::to_string),
+    };
+    if let Ok(serialized) = serde_json::to_string(&entry) {
+        let _ = std::fs::write(path, serialized);
+    }
+}
+
+/// Resets an entry's modification time to now, keeping its existing value
+/// and `ETag`. Used when a `304 Not Modified` confirms a stale entry is
+/// still current, so the TTL window restarts without re-fetching the body.
+pub fn touch(namespace: &str, key: &str) {
+    if let Some(path) = entry_path(namespace, key) {
+        if let Ok(contents) = std::fs::read(&path) {
+            let _ = std::fs::write(&path, contents);
+        }
+    }
+}
+
+fn read_entry<T: DeserializeOwned>(path: &PathBuf) -> Option<CacheEntry<T>> {
+    let contents = std::fs::read_to_string(path).ok()?;
+    serde_json::from_str(&contents).ok()
+}
+
+fn entry_path(namespace: &str, key: &str) -> Option<PathBuf> {
+    let root = dirs::cache_dir()?.join(CACHE_APP_DIR);
+    let file_name = format!("{}.json", key.replace(['/', ':', ' '], "_"));
+    Some(root.join(namespace).join(file_name))
+}
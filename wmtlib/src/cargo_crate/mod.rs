@@ -1,11 +1,19 @@
+use std::collections::HashMap;
 use std::option::Option;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, OnceLock};
 
 use anyhow::{anyhow, Result};
 use cargo_toml::{DependencyDetail, DepsSet, Manifest};
 use chrono::{DateTime, Utc};
-use crates_io_api::{CrateResponse, SyncClient};
+use crates_io_api::{AsyncClient, CrateResponse};
+use futures::future::join_all;
 
-use crate::constants::{CRATES_API_RPS, CRATES_API_USER_AGENT, MISSING_FIELD_PLACEHOLDER};
+use crate::constants::{
+    CRATES_API_RPS, CRATES_API_USER_AGENT, MISSING_FIELD_PLACEHOLDER,
+    RATE_LIMIT_BURST_CAPACITY, RATE_LIMIT_STEADY_PER_SECOND,
+};
+use crate::rate_limit::RateLimiter;
 
 #[derive(Debug)]
 pub struct CrateVersion {
@@ -13,6 +21,28 @@ pub struct CrateVersion {
     pub remote: Option<String>,
 }
 
+/// Which `Cargo.toml` table a dependency was declared in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DependencyKind {
+    Normal,
+    Development,
+    Build,
+}
+
+/// One `[[package]]` entry parsed from a `Cargo.lock`, keyed by
+/// `(name, version)` once resolved. Each dependency edge keeps the version
+/// the lockfile pinned it to when one is present (Cargo only disambiguates
+/// an edge with a version when more than one resolved version of that name
+/// exists), so a walk can follow the exact edge it declared rather than
+/// every same-named package anywhere in the lockfile.
+#[derive(Debug, Clone)]
+struct LockedPackage {
+    name: String,
+    version: String,
+    source: Option<String>,
+    dependencies: Vec<(String, Option<String>)>,
+}
+
 #[derive(Debug)]
 pub struct Crate {
     pub name: String,
@@ -24,6 +54,11 @@ pub struct Crate {
     pub created_at: Option<DateTime<Utc>>,
     pub updated_at: Option<DateTime<Utc>>,
     pub downloads: u64,
+    pub kind: DependencyKind,
+    /// The direct (root) dependency whose subtree pulled this crate in,
+    /// when it was resolved via [`Crate::from_lockfile`]'s transitive walk.
+    /// `None` for direct dependencies and for crates resolved any other way.
+    pub introduced_by: Option<String>,
 }
 
 impl Crate {
@@ -38,26 +73,205 @@ impl Crate {
             created_at: None,
             updated_at: None,
             downloads: 0,
+            kind: DependencyKind::Normal,
+            introduced_by: None,
         }
     }
 
-    pub fn from_manifest(manifest_file: String) -> Vec<Self> {
-        let mut dependencies = Vec::new();
-        let manifest_content = Self::extract_dependencies_from_manifest(manifest_file);
+    /// Resolves every dependency reachable from `manifest_file`: the
+    /// `[dependencies]`/`[dev-dependencies]`/`[build-dependencies]` (and
+    /// their `[target.*]` equivalents) of the manifest itself, plus the same
+    /// tables for every `[workspace].members` package, deduplicated by
+    /// `(name, kind)`. When a sibling `Cargo.lock` exists, its pinned version
+    /// is preferred over the manifest-declared requirement.
+    pub async fn from_manifest(manifest_file: String) -> Vec<Self> {
+        let manifest_path = PathBuf::from(&manifest_file);
+        let locked_versions = Self::read_locked_versions(&manifest_path);
+        let manifest_content = Self::extract_dependencies_from_manifest(&manifest_path);
 
-        for dep in manifest_content {
-            let dependency_without_version = Self::dependency_without_version();
+        let pending = manifest_content.into_iter().map(|(name, detail, kind)| {
+            let local_version = locked_versions.get(&name).cloned().or(detail.version);
+            async move {
+                let mut dependency = Crate::from_name(name, local_version).await;
+                dependency.kind = kind;
+                dependency
+            }
+        });
 
-            let local_version = &dep
-                .1
-                .detail()
-                .unwrap_or(&dependency_without_version)
-                .version;
-            let full_dependency = Crate::from_name(dep.0, local_version.to_owned());
-            dependencies.push(full_dependency)
+        join_all(pending).await
+    }
+
+    /// Resolves the *entire* transitive dependency graph reachable from
+    /// `manifest_file`'s `Cargo.lock`, rather than just its direct
+    /// dependencies. Starts from the manifest's (and any workspace members')
+    /// declared dependencies — optionally including `[dev-dependencies]`
+    /// when `include_dev` is set — and walks the lockfile's dependency
+    /// edges from there, skipping workspace-local path packages (which have
+    /// no registry `source` in the lockfile) and deduplicating by
+    /// `(name, version)`. Returns `None` when there's no `Cargo.lock` next
+    /// to `manifest_file`, so callers can fall back to direct-dependency
+    /// resolution.
+    pub async fn from_lockfile(manifest_file: String, include_dev: bool) -> Option<Vec<Self>> {
+        let manifest_path = PathBuf::from(&manifest_file);
+        let lock_path = manifest_path.with_file_name("Cargo.lock");
+        let packages = Self::parse_lockfile(&lock_path)?;
+
+        let mut by_name: HashMap<&str, Vec<&LockedPackage>> = HashMap::new();
+        for package in &packages {
+            by_name
+                .entry(package.name.as_str())
+                .or_default()
+                .push(package);
         }
 
-        dependencies
+        let roots: Vec<String> = Self::extract_dependencies_from_manifest(&manifest_path)
+            .into_iter()
+            .filter(|(_, _, kind)| include_dev || *kind != DependencyKind::Development)
+            .map(|(name, _, _)| name)
+            .collect();
+
+        // (crate, puller) — `puller` is the root dependency whose subtree
+        // is walking this edge, carried along so the first root to reach a
+        // package gets credit for having introduced it.
+        let mut visited: HashMap<(String, String), &LockedPackage> = HashMap::new();
+        let mut introduced_by: HashMap<(String, String), String> = HashMap::new();
+        let mut queue: Vec<(&str, Option<&str>, String)> = roots
+            .iter()
+            .map(|name| (name.as_str(), None, name.clone()))
+            .collect();
+
+        while let Some((name, version, root)) = queue.pop() {
+            let package = match Self::resolve_edge(&by_name, name, version) {
+                Some(package) => package,
+                // An edge with no unambiguous match in the lockfile (e.g. a
+                // version-less edge when more than one resolved version of
+                // that name exists) can't be followed safely — skip it
+                // rather than guessing at every same-named candidate.
+                None => continue,
+            };
+
+            // Workspace-local path packages have no registry source.
+            if package.source.is_none() {
+                continue;
+            }
+
+            let key = (package.name.clone(), package.version.clone());
+            if visited.contains_key(&key) {
+                continue;
+            }
+            visited.insert(key.clone(), package);
+            introduced_by.insert(key, root.clone());
+
+            for (dependency_name, dependency_version) in &package.dependencies {
+                queue.push((dependency_name.as_str(), dependency_version.as_deref(), root.clone()));
+            }
+        }
+
+        let pending = visited.into_values().map(|package| {
+            let name = package.name.clone();
+            let version = package.version.clone();
+            let root = introduced_by.get(&(name.clone(), version.clone())).cloned();
+            // A root is its own puller — only surface attribution for
+            // crates some other root actually pulled in transitively.
+            let introduced_by = root.filter(|root| *root != name);
+            async move {
+                let mut dependency = Crate::from_name(name, Some(version)).await;
+                dependency.introduced_by = introduced_by;
+                dependency
+            }
+        });
+
+        Some(join_all(pending).await)
+    }
+
+    /// Resolves a dependency edge `(name, version)` to the `LockedPackage`
+    /// it refers to. Cargo.lock only records a version on an edge when the
+    /// name alone is ambiguous, so a version-less edge falls back to the
+    /// single candidate with that name, if there's exactly one.
+    fn resolve_edge<'a>(
+        by_name: &HashMap<&str, Vec<&'a LockedPackage>>,
+        name: &str,
+        version: Option<&str>,
+    ) -> Option<&'a LockedPackage> {
+        let candidates = by_name.get(name)?;
+        match version {
+            Some(version) => candidates
+                .iter()
+                .find(|package| package.version == version)
+                .copied(),
+            None if candidates.len() == 1 => candidates.first().copied(),
+            None => None,
+        }
+    }
+
+    /// Parses a `Cargo.lock`'s `[[package]]` entries into name/version/
+    /// source/dependency-edge records. Returns `None` if the file doesn't
+    /// exist.
+    fn parse_lockfile(lock_path: &Path) -> Option<Vec<LockedPackage>> {
+        let contents = std::fs::read_to_string(lock_path).ok()?;
+
+        let mut packages = Vec::new();
+        let mut current: Option<LockedPackage> = None;
+        let mut in_dependencies = false;
+
+        for raw_line in contents.lines() {
+            let line = raw_line.trim();
+
+            if line == "[[package]]" {
+                if let Some(package) = current.take() {
+                    packages.push(package);
+                }
+                current = Some(LockedPackage {
+                    name: String::new(),
+                    version: String::new(),
+                    source: None,
+                    dependencies: Vec::new(),
+                });
+                in_dependencies = false;
+                continue;
+            }
+
+            let package = match current.as_mut() {
+                Some(package) => package,
+                None => continue,
+            };
+
+            if in_dependencies {
+                if line == "]" {
+                    in_dependencies = false;
+                } else {
+                    let entry = line.trim_matches(|c: char| c == '"' || c == ',');
+                    let mut parts = entry.split_whitespace();
+                    let dependency_name = parts.next().unwrap_or(entry);
+                    // Present only when the name alone is ambiguous, e.g.
+                    // `"serde 1.0.130 (registry+https://...)"`; a plain
+                    // `"serde"` means there's just one resolved version.
+                    let dependency_version = parts.next().map(str::to_string);
+                    if !dependency_name.is_empty() {
+                        package
+                            .dependencies
+                            .push((dependency_name.to_string(), dependency_version));
+                    }
+                }
+                continue;
+            }
+
+            if let Some(value) = line.strip_prefix("name = ") {
+                package.name = value.trim_matches('"').to_string();
+            } else if let Some(value) = line.strip_prefix("version = ") {
+                package.version = value.trim_matches('"').to_string();
+            } else if let Some(value) = line.strip_prefix("source = ") {
+                package.source = Some(value.trim_matches('"').to_string());
+            } else if line.starts_with("dependencies = [") {
+                in_dependencies = true;
+            }
+        }
+
+        if let Some(package) = current.take() {
+            packages.push(package);
+        }
+
+        Some(packages)
     }
 
     fn dependency_without_version() -> DependencyDetail {
@@ -77,9 +291,13 @@ impl Crate {
         }
     }
 
-    pub fn from_name(name: String, local_version: Option<String>) -> Self {
+    pub async fn from_name(name: String, local_version: Option<String>) -> Self {
         let crate_client = CratesService::new();
-        let crate_info = crate_client.get_crate(name.as_str()).unwrap().crate_data;
+        let crate_info = crate_client
+            .get_crate(name.as_str())
+            .await
+            .unwrap()
+            .crate_data;
 
         Crate {
             name,
@@ -94,34 +312,222 @@ impl Crate {
             created_at: Option::from(crate_info.created_at),
             updated_at: Option::from(crate_info.updated_at),
             downloads: crate_info.downloads,
+            kind: DependencyKind::Normal,
+            introduced_by: None,
+        }
+    }
+
+    /// Walks the manifest at `path` and, if it declares a `[workspace]`,
+    /// every member's manifest too, merging all dependency tables keyed by
+    /// `(name, kind)` so the same crate declared the same way twice only
+    /// shows up once.
+    fn extract_dependencies_from_manifest(
+        path: &Path,
+    ) -> Vec<(String, DependencyDetail, DependencyKind)> {
+        let manifest = Manifest::from_path(path).unwrap();
+        let root_dir = path.parent().unwrap_or_else(|| Path::new("."));
+
+        let mut merged: HashMap<(String, DependencyKind), DependencyDetail> = HashMap::new();
+        Self::merge_manifest_deps(&manifest, &mut merged);
+
+        if let Some(workspace) = &manifest.workspace {
+            for member_dir in Self::expand_workspace_members(root_dir, &workspace.members) {
+                if let Ok(member_manifest) = Manifest::from_path(member_dir.join("Cargo.toml")) {
+                    Self::merge_manifest_deps(&member_manifest, &mut merged);
+                }
+            }
         }
+
+        merged
+            .into_iter()
+            .map(|((name, kind), detail)| (name, detail, kind))
+            .collect()
     }
 
-    fn extract_dependencies_from_manifest(path: String) -> DepsSet {
-        Manifest::from_path(path).unwrap().dependencies
+    /// Merges a manifest's `[dependencies]`, `[dev-dependencies]`,
+    /// `[build-dependencies]`, and every `[target.*]` equivalent into
+    /// `merged`, keeping the first detail seen for a given `(name, kind)`.
+    fn merge_manifest_deps(
+        manifest: &Manifest,
+        merged: &mut HashMap<(String, DependencyKind), DependencyDetail>,
+    ) {
+        Self::merge_dep_tables(
+            &manifest.dependencies,
+            &manifest.dev_dependencies,
+            &manifest.build_dependencies,
+            merged,
+        );
+
+        for target in manifest.target.values() {
+            Self::merge_dep_tables(
+                &target.dependencies,
+                &target.dev_dependencies,
+                &target.build_dependencies,
+                merged,
+            );
+        }
+    }
+
+    fn merge_dep_tables(
+        dependencies: &DepsSet,
+        dev_dependencies: &DepsSet,
+        build_dependencies: &DepsSet,
+        merged: &mut HashMap<(String, DependencyKind), DependencyDetail>,
+    ) {
+        let dependency_without_version = Self::dependency_without_version();
+        for (table, kind) in [
+            (dependencies, DependencyKind::Normal),
+            (dev_dependencies, DependencyKind::Development),
+            (build_dependencies, DependencyKind::Build),
+        ] {
+            for (name, dep) in table {
+                let detail = dep
+                    .detail()
+                    .unwrap_or(&dependency_without_version)
+                    .to_owned();
+                merged.entry((name.to_owned(), kind)).or_insert(detail);
+            }
+        }
+    }
+
+    /// Expands `[workspace].members` glob patterns (only the common
+    /// `dir/*` trailing-wildcard form) relative to `root_dir` into concrete
+    /// package directories.
+    fn expand_workspace_members(root_dir: &Path, patterns: &[String]) -> Vec<PathBuf> {
+        let mut members = Vec::new();
+        for pattern in patterns {
+            match pattern.strip_suffix("/*") {
+                Some(prefix) => {
+                    if let Ok(entries) = std::fs::read_dir(root_dir.join(prefix)) {
+                        for entry in entries.flatten() {
+                            let path = entry.path();
+                            if path.join("Cargo.toml").is_file() {
+                                members.push(path);
+                            }
+                        }
+                    }
+                }
+                None => members.push(root_dir.join(pattern)),
+            }
+        }
+        members
+    }
+
+    /// Reads the exact versions the `Cargo.lock` next to `path` resolved
+    /// each locally-declared dependency to, keyed by dependency name.
+    /// Rather than keying the whole lockfile by bare name — which silently
+    /// lets whichever `[[package]]` block happens to come last win whenever
+    /// two different versions of the same crate are resolved somewhere in
+    /// the lockfile — this looks up each local package's (the manifest
+    /// itself, plus any workspace members) own `[[package]]` entry and
+    /// resolves *its* dependency edges with [`Crate::resolve_edge`], the
+    /// same edge-aware lookup `from_lockfile`'s transitive walk uses.
+    fn read_locked_versions(path: &Path) -> HashMap<String, String> {
+        let mut locked = HashMap::new();
+
+        let packages = match Self::parse_lockfile(&path.with_file_name("Cargo.lock")) {
+            Some(packages) => packages,
+            None => return locked,
+        };
+
+        let mut by_name: HashMap<&str, Vec<&LockedPackage>> = HashMap::new();
+        for package in &packages {
+            by_name.entry(package.name.as_str()).or_default().push(package);
+        }
+
+        let mut local_manifest_paths = vec![path.to_path_buf()];
+        if let Ok(manifest) = Manifest::from_path(path) {
+            if let Some(workspace) = &manifest.workspace {
+                let root_dir = path.parent().unwrap_or_else(|| Path::new("."));
+                local_manifest_paths.extend(
+                    Self::expand_workspace_members(root_dir, &workspace.members)
+                        .into_iter()
+                        .map(|member_dir| member_dir.join("Cargo.toml")),
+                );
+            }
+        }
+
+        for manifest_path in &local_manifest_paths {
+            let local_name = match Manifest::from_path(manifest_path)
+                .ok()
+                .and_then(|manifest| manifest.package)
+            {
+                Some(package) => package.name,
+                // No `[package]` table (e.g. a virtual workspace manifest)
+                // has no lockfile entry of its own to read edges from.
+                None => continue,
+            };
+
+            let local_package = match by_name.get(local_name.as_str()) {
+                Some(candidates) if candidates.len() == 1 => candidates[0],
+                _ => continue,
+            };
+
+            for (name, version) in &local_package.dependencies {
+                if let Some(package) = Self::resolve_edge(&by_name, name, version.as_deref()) {
+                    locked.insert(name.clone(), package.version.clone());
+                }
+            }
+        }
+
+        locked
     }
 }
 
+#[derive(Clone)]
 pub struct CratesService {
-    client: SyncClient,
+    client: Arc<AsyncClient>,
+    /// `crates_io_api::AsyncClient`'s own throttle is a fixed interval with
+    /// no backoff, so every call is also gated through an adaptive
+    /// token-bucket `RateLimiter` of its own, built with the same steady
+    /// rate and burst capacity `HTTPClient` uses for its requests, to back off
+    /// properly under sustained load (e.g. a full transitive-graph sweep).
+    /// Shared process-wide (see `shared()` below) so that backoff state
+    /// actually survives from one crate lookup to the next instead of
+    /// resetting on every `CratesService::new()` call.
+    rate_limiter: Arc<RateLimiter>,
 }
 
+static SHARED_CRATES_SERVICE: OnceLock<CratesService> = OnceLock::new();
+
 impl CratesService {
+    /// Returns the process-wide `CratesService`, creating it on first use.
+    /// Cloning it is cheap: both the underlying client and the rate
+    /// limiter are reference-counted, so every caller shares the same
+    /// adaptive backoff state.
     pub fn new() -> Self {
-        let client = SyncClient::new(
+        SHARED_CRATES_SERVICE.get_or_init(Self::build).clone()
+    }
+
+    fn build() -> Self {
+        let client = AsyncClient::new(
             CRATES_API_USER_AGENT,
             std::time::Duration::from_millis(CRATES_API_RPS),
         )
-            .unwrap();
-        CratesService { client }
+        .unwrap();
+        CratesService {
+            client: Arc::new(client),
+            rate_limiter: Arc::new(RateLimiter::new(
+                RATE_LIMIT_STEADY_PER_SECOND,
+                RATE_LIMIT_BURST_CAPACITY,
+            )),
+        }
     }
 
-    pub fn get_crate(&self, crate_name: &str) -> Result<CrateResponse> {
-        return match self.client.get_crate(crate_name) {
-            Ok(response) => Ok(response),
+    pub async fn get_crate(&self, crate_name: &str) -> Result<CrateResponse> {
+        if let Some(cached) = crate::cache::get::<CrateResponse>("crates_io", crate_name) {
+            return Ok(cached);
+        }
+
+        self.rate_limiter.acquire().await;
+        match self.client.get_crate(crate_name).await {
+            Ok(response) => {
+                crate::cache::set("crates_io", crate_name, &response);
+                Ok(response)
+            }
             Err(_) => Err(anyhow!(
                 "Could not retrieve the crate information. The crates.io API might be down."
             )),
-        };
+        }
     }
 }
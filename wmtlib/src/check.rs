@@ -4,19 +4,26 @@ use std::string::String;
 use std::vec::IntoIter;
 
 use chrono::Utc;
-use octocrab::models::issues::Issue;
+use futures::future::join_all;
+use futures::stream::{self, StreamExt};
 use octocrab::models::workflows::WorkFlow;
-use octocrab::params::State;
 use serde::Serialize;
 
 use crate::{CommandResult, log_if_verbose, Question, Questions};
-use crate::cargo_crate::Crate;
+use crate::cargo_crate::{Crate, CratesService};
+use crate::check_config::CheckConfig;
 use crate::constants::{
-    MAX_DOWNLOAD_FOR_MINOR_VERSION, MISSING_FIELD_PLACEHOLDER, QUESTION_EXPLANATION_SUFFIX,
+    CHURN_NEAR_ZERO_THRESHOLD, DEFAULT_CHECK_CONCURRENCY, MAINTAINER_RESPONSE_ISSUE_SAMPLE_SIZE,
+    MAINTAINER_RESPONSE_THRESHOLD_HOURS, MAX_DOWNLOAD_FOR_MINOR_VERSION, MISSING_FIELD_PLACEHOLDER,
+    QUESTION_EXPLANATION_SUFFIX, RELEASE_CADENCE_SAMPLE_SIZE, RELEASE_CADENCE_THRESHOLD_DAYS,
 };
 use crate::doc::{DocService, DocSource};
 use crate::github::GithubService;
+use crate::provider::provider_for;
 use crate::questions::CheckNames;
+use crate::release_date::ReleaseDate;
+use crate::release_order;
+use crate::source_churn;
 use crate::version::Version;
 
 /// Represents a dependency
@@ -25,13 +32,16 @@ pub struct CrateCheck {
     pub crates: Vec<Crate>,
     pub results: Option<Vec<Vec<CheckResult>>>,
     pub verbose: bool,
+    pub concurrency: usize,
+    pub config: CheckConfig,
+    runtime: tokio::runtime::Runtime,
 }
 
 /// Represents the status of a dependency check.
 /// GREEN ===> Completely passes the required test.
 /// Yellow ====> Passes the required test but not completely.
 /// RED =====> Does not pass the required test
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Serialize)]
 pub enum Status {
     Green,
     Yellow,
@@ -51,16 +61,27 @@ impl Status {
 }
 
 ///Represents the result of running checking a question
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Serialize)]
 pub struct CheckResult {
     question: Option<String>,
+    crate_name: Option<String>,
     status: Status,
     explanation: String,
+    /// The direct dependency that pulled `crate_name` in transitively, when
+    /// this result came from a [`Crate`] resolved via `--transitive`. `None`
+    /// for direct dependencies.
+    introduced_by: Option<String>,
 }
 
 impl CheckResult {
     pub fn to_vec(&self) -> Vec<String> {
+        let crate_name = match (&self.crate_name, &self.introduced_by) {
+            (Some(crate_name), Some(root)) => format!("{} (via {})", crate_name, root),
+            (crate_name, _) => crate_name.to_owned().unwrap_or_default(),
+        };
+
         return vec![
+            crate_name,
             self.question.to_owned().unwrap(),
             self.status.value(),
             self.explanation.to_string(),
@@ -70,69 +91,221 @@ impl CheckResult {
     pub fn from_error_message(message: String) -> Self {
         CheckResult {
             question: None,
+            crate_name: None,
             status: Status::Red,
             explanation: message,
+            introduced_by: None,
+        }
+    }
+
+    /// Whether this check failed outright, used to compute a CI exit code.
+    pub fn is_failing(&self) -> bool {
+        matches!(self.status, Status::Red)
+    }
+
+    /// Whether this check only partially passed.
+    pub fn is_yellow(&self) -> bool {
+        matches!(self.status, Status::Yellow)
+    }
+
+    /// The question number this result belongs to (e.g. `"3"`), used to
+    /// look up a per-question weight in a `ScoringConfig`.
+    pub fn question_number(&self) -> Option<&str> {
+        self.question.as_deref()
+    }
+
+    /// The name of the result's crate, used to group results by crate.
+    pub fn crate_name(&self) -> Option<&str> {
+        self.crate_name.as_deref()
+    }
+
+    /// The direct dependency that pulled this result's crate in
+    /// transitively, if any.
+    pub fn introduced_by(&self) -> Option<&str> {
+        self.introduced_by.as_deref()
+    }
+
+    /// This check's contribution to a repo's overall health score: full
+    /// credit for Green, half for Yellow, none for Red, and excluded
+    /// entirely (`None`) for Grey (unsupported) checks.
+    pub fn score(&self) -> Option<f64> {
+        match self.status {
+            Status::Green => Some(1.0),
+            Status::Yellow => Some(0.5),
+            Status::Red => Some(0.0),
+            Status::Grey => None,
+        }
+    }
+}
+
+/// Whether `source_url` points at GitHub — used to gate checks that are
+/// still implemented against `GithubService` directly rather than through
+/// the forge-agnostic `RepoProvider` trait.
+fn is_github_source(source_url: &str) -> bool {
+    source_url.contains("github.com")
+}
+
+/// Regroups flattened check results so every result for a given crate sorts
+/// together, in first-seen crate order. `run_checks` submits its tasks in
+/// per-crate order, but `buffer_unordered` yields them back in whatever
+/// order they finish, so without this a multi-crate run's output would be
+/// interleaved rather than grouped by crate.
+fn group_by_crate(data: Vec<Vec<CheckResult>>) -> Vec<Vec<CheckResult>> {
+    let mut order: Vec<String> = Vec::new();
+    let mut by_crate: HashMap<String, Vec<CheckResult>> = HashMap::new();
+
+    for result in data.into_iter().flatten() {
+        let crate_name = result.crate_name.clone().unwrap_or_default();
+        if !by_crate.contains_key(&crate_name) {
+            order.push(crate_name.clone());
         }
+        by_crate.entry(crate_name).or_default().push(result);
+    }
+
+    order
+        .into_iter()
+        .filter_map(|crate_name| by_crate.remove(&crate_name))
+        .collect()
+}
+
+/// Sorts `values` in place and returns the median, or `None` if empty.
+fn median(values: &mut [i64]) -> Option<i64> {
+    if values.is_empty() {
+        return None;
+    }
+
+    values.sort_unstable();
+    let mid = values.len() / 2;
+    if values.len() % 2 == 0 {
+        Some((values[mid - 1] + values[mid]) / 2)
+    } else {
+        Some(values[mid])
     }
 }
 
 impl CrateCheck {
+    /// Builds the crates to check from `deps`. When `transitive` is set and
+    /// a `dep` is a manifest path with an adjacent `Cargo.lock`, every crate
+    /// reachable in the resolved dependency graph is checked instead of just
+    /// the manifest's direct dependencies (`include_dev_dependencies`
+    /// controls whether `[dev-dependencies]` count as roots of that walk).
+    /// Falls back to direct-dependency resolution when no `Cargo.lock`
+    /// exists next to the manifest.
     #[tracing::instrument]
-    pub fn new(deps: Vec<String>, verbose: bool) -> Self {
+    pub fn new(
+        deps: Vec<String>,
+        verbose: bool,
+        concurrency: Option<usize>,
+        transitive: bool,
+        include_dev_dependencies: bool,
+        config: Option<CheckConfig>,
+    ) -> Self {
         log_if_verbose(verbose, "Checking dependency source");
-        let mut crates_to_check = Vec::new();
-        for dep in deps {
-            match dep.ends_with(".toml") {
-                true => {
-                    log_if_verbose(verbose, "Found manifest path. Extracting");
-                    let dependencies_from_manifest = Crate::from_manifest(dep);
-                    crates_to_check.extend(dependencies_from_manifest);
-                }
-                false => match dep.starts_with("https://") {
+        let runtime = tokio::runtime::Runtime::new().expect("Could not start the tokio runtime");
+
+        let crates_to_check = runtime.block_on(async {
+            let mut crates_to_check = Vec::new();
+            for dep in deps {
+                match dep.ends_with(".toml") {
                     true => {
-                        log_if_verbose(verbose, "Found source url. Querying");
-                        let dependency = Crate::from_source(dep);
-                        crates_to_check.push(dependency);
-                    }
-                    false => {
-                        log_if_verbose(verbose, "Found crate name. Extracting crate information");
-                        let dependency =
-                            Crate::from_name(dep, Some(MISSING_FIELD_PLACEHOLDER.to_string()));
-                        crates_to_check.push(dependency);
+                        log_if_verbose(verbose, "Found manifest path. Extracting");
+                        let dependencies_from_manifest = if transitive {
+                            match Crate::from_lockfile(dep.clone(), include_dev_dependencies).await
+                            {
+                                Some(graph) => graph,
+                                None => {
+                                    log_if_verbose(
+                                        verbose,
+                                        "No Cargo.lock found. Falling back to direct dependencies",
+                                    );
+                                    Crate::from_manifest(dep).await
+                                }
+                            }
+                        } else {
+                            Crate::from_manifest(dep).await
+                        };
+                        crates_to_check.extend(dependencies_from_manifest);
                     }
-                },
+                    false => match dep.starts_with("https://") {
+                        true => {
+                            log_if_verbose(verbose, "Found source url. Querying");
+                            let dependency = Crate::from_source(dep);
+                            crates_to_check.push(dependency);
+                        }
+                        false => {
+                            log_if_verbose(
+                                verbose,
+                                "Found crate name. Extracting crate information",
+                            );
+                            let dependency = Crate::from_name(
+                                dep,
+                                Some(MISSING_FIELD_PLACEHOLDER.to_string()),
+                            )
+                            .await;
+                            crates_to_check.push(dependency);
+                        }
+                    },
+                }
             }
-        }
+            crates_to_check
+        });
 
         CrateCheck {
             crates: crates_to_check,
             results: Option::None,
             verbose,
+            // `buffer_unordered(0)` never polls its stream and hangs forever,
+            // so a `0` is treated the same as "not specified".
+            concurrency: match concurrency {
+                Some(0) | None => DEFAULT_CHECK_CONCURRENCY,
+                Some(requested) => requested,
+            },
+            config: config.unwrap_or_default(),
+            runtime,
         }
     }
 
-    /// Show the command result
-    pub fn show_results(&self, json: bool, data: Vec<Vec<CheckResult>>) {
-        let command_result = CommandResult {
-            as_json: json,
-            headers: vec![
-                String::from("Question"),
-                String::from("Status"),
-                String::from("Explanation"),
-            ],
-        };
+    /// Show the command result in the given `format` ("table", "json",
+    /// "markdown", or "sarif" — anything else falls back to "table").
+    pub fn show_results(&self, format: &str, data: Vec<Vec<CheckResult>>) {
+        let data = group_by_crate(data);
+        match format {
+            "markdown" => println!("{}", crate::result::render_markdown(&data)),
+            "sarif" => println!("{}", crate::result::render_sarif(&data)),
+            _ => {
+                let command_result = CommandResult {
+                    as_json: format == "json",
+                    headers: vec![
+                        String::from("Crate"),
+                        String::from("Question"),
+                        String::from("Status"),
+                        String::from("Explanation"),
+                    ],
+                };
+
+                command_result.display_checks_result(data)
+            }
+        }
+    }
 
-        command_result.display_checks_result(data)
+    /// A CI-friendly exit code for a completed set of check results: `0` if
+    /// every check passed or was unsupported, `1` if any check failed.
+    pub fn exit_code(&self, data: &[Vec<CheckResult>]) -> i32 {
+        let has_failure = data.iter().flatten().any(CheckResult::is_failing);
+        i32::from(has_failure)
     }
 
-    /// Run the checks on a question or list of questions
+    /// Run the checks on a question or list of questions, driving every
+    /// crate/question combination concurrently on the shared runtime, capped
+    /// at `self.concurrency` in-flight requests at a time.
     pub fn run_checks(&self, question: Option<String>) -> Vec<Vec<CheckResult>> {
         let question = question.unwrap_or_else(|| "0".parse().unwrap());
         let questions = Questions {
             verbose: self.verbose,
+            questions_path: self.config.questions_path.clone(),
         };
 
-        let selected_question = match question.as_str() {
+        let selected_question: Vec<Question> = match question.as_str() {
             "0" => {
                 log_if_verbose(self.verbose, "Will check all questions");
                 questions.list().questions
@@ -144,16 +317,17 @@ impl CrateCheck {
                 );
                 questions.describe(question)
             }
-        };
-
-        let mut results = Vec::new();
+        }
+        .into_iter()
+        .filter(|question| self.config.is_enabled(&question.number))
+        .collect();
 
+        let mut tasks = Vec::new();
         for cargo_crate in &self.crates {
             match cargo_crate.source_url.is_some() {
                 true => {
                     for question_to_check in &selected_question {
-                        let question_results = self.check_question(cargo_crate, question_to_check);
-                        results.push(question_results);
+                        tasks.push(self.check_question(cargo_crate, question_to_check));
                     }
                 }
                 false => {
@@ -165,18 +339,25 @@ impl CrateCheck {
                 }
             }
         }
-        results
+
+        self.runtime.block_on(
+            stream::iter(tasks)
+                .buffer_unordered(self.concurrency)
+                .collect::<Vec<_>>(),
+        )
     }
 
     fn no_support_result(&self) -> CheckResult {
         CheckResult {
             question: None,
+            crate_name: None,
             status: Status::Grey,
             explanation: "This is currently not supported".to_string(),
+            introduced_by: None,
         }
     }
 
-    fn check_question(&self, cargo_crate: &Crate, question: &Question) -> Vec<CheckResult> {
+    async fn check_question(&self, cargo_crate: &Crate, question: &Question) -> Vec<CheckResult> {
         let mut check_results: Vec<CheckResult> = Vec::new();
 
         let mut check_result = match question.name {
@@ -186,51 +367,81 @@ impl CrateCheck {
             }
             CheckNames::Documentation => {
                 log_if_verbose(self.verbose, "Checking for documentation");
-                self.check_documentation(cargo_crate)
+                self.check_documentation(cargo_crate).await
             }
             CheckNames::Changelog => {
                 log_if_verbose(self.verbose, "Checking for changelog");
-                self.check_changelog(cargo_crate)
+                self.check_changelog(cargo_crate).await
             }
             CheckNames::Tests => {
                 log_if_verbose(self.verbose, "Checking for tests");
-                self.check_tests(cargo_crate)
+                self.check_tests(cargo_crate).await
             }
             CheckNames::BugReportResponse => {
                 log_if_verbose(self.verbose, "Checking for bug response time");
-                self.check_bug_response(cargo_crate)
+                self.check_bug_response(cargo_crate).await
             }
             CheckNames::TestsRunAgainstLatestLanguageVersion => {
                 log_if_verbose(
                     self.verbose,
                     "Checking if the tests run with the latest <Language> version?",
                 );
-                self.check_runs_against_latest_language(cargo_crate)
+                self.check_runs_against_latest_language(cargo_crate).await
             }
             CheckNames::TestsRunAgainstLatestIntegrationVersion => self.no_support_result(),
             CheckNames::ContinuousIntegrationConfiguration => {
                 log_if_verbose(self.verbose, "Checking for Github workflows");
-                self.check_continuous_integration(cargo_crate)
+                self.check_continuous_integration(cargo_crate).await
             }
             CheckNames::ContinuousIntegrationPasses => {
                 log_if_verbose(self.verbose, "Checking for CI status");
-                self.check_ci_status(cargo_crate)
+                self.check_ci_status(cargo_crate).await
             }
             CheckNames::Usage => {
                 log_if_verbose(self.verbose, "Checking for usage data");
-                self.check_usage(cargo_crate)
+                self.check_usage(cargo_crate).await
             }
             CheckNames::LatestCommits => {
                 log_if_verbose(self.verbose, "Checking for latest commits");
-                self.check_latest_commits(cargo_crate)
+                self.check_latest_commits(cargo_crate).await
             }
             CheckNames::LatestRelease => {
                 log_if_verbose(self.verbose, "Checking for latest release");
-                self.check_latest_release(cargo_crate)
+                self.check_latest_release(cargo_crate).await
+            }
+            CheckNames::DeadLinks => {
+                log_if_verbose(self.verbose, "Checking for dead links in the README");
+                self.check_dead_links(cargo_crate).await
+            }
+            CheckNames::WebhookDeliveryHealth => {
+                log_if_verbose(self.verbose, "Checking webhook delivery health");
+                self.check_webhook_delivery_health(cargo_crate).await
+            }
+            CheckNames::OutdatedDependency => {
+                log_if_verbose(self.verbose, "Checking for an outdated dependency version");
+                self.check_outdated(cargo_crate).await
+            }
+            CheckNames::ReleaseCadence => {
+                log_if_verbose(self.verbose, "Checking release cadence");
+                self.check_release_cadence(cargo_crate).await
+            }
+            CheckNames::SourceChurn => {
+                log_if_verbose(self.verbose, "Checking source churn between releases");
+                self.check_source_churn(cargo_crate).await
+            }
+            CheckNames::DocumentationBuildTargets => {
+                log_if_verbose(self.verbose, "Checking per-target documentation build status");
+                self.check_documentation_build_targets(cargo_crate).await
+            }
+            CheckNames::VersionHealth => {
+                log_if_verbose(self.verbose, "Checking version timeline and yank health");
+                self.check_version_health(cargo_crate).await
             }
         };
 
         check_result.question = Option::from(question.number.to_owned());
+        check_result.crate_name = Option::from(cargo_crate.name.to_owned());
+        check_result.introduced_by = cargo_crate.introduced_by.to_owned();
         check_results.push(check_result);
 
         check_results
@@ -248,6 +459,8 @@ impl CrateCheck {
                 log_if_verbose(self.verbose, "Passes AT_LEAST_ONE_MAJOR_RELEASE check");
                 CheckResult {
                     question: None,
+                    crate_name: None,
+                    introduced_by: None,
                     status: Status::Green,
                     explanation: format!(
                         "{} at least one major release",
@@ -266,6 +479,8 @@ impl CrateCheck {
                             );
                             CheckResult {
                                 question: None,
+                                crate_name: None,
+                                introduced_by: None,
                                 status: Status::Yellow,
                                 explanation: format!(
                                     "{} at least two minor releases and at least 500 downloads",
@@ -277,6 +492,8 @@ impl CrateCheck {
                             log_if_verbose(self.verbose, "Does not pass any of the checks.");
                             CheckResult {
                                 question: None,
+                                crate_name: None,
+                                introduced_by: None,
                                 status: Status::Red,
                                 explanation: format!(
                                     "{} no major or minor release",
@@ -290,6 +507,8 @@ impl CrateCheck {
                     log_if_verbose(self.verbose, "Does not pass any of the checks.");
                     CheckResult {
                         question: None,
+                        crate_name: None,
+                        introduced_by: None,
                         status: Status::Red,
                         explanation: format!(
                             "{} no major or minor release",
@@ -305,38 +524,71 @@ impl CrateCheck {
     /// 1. Does the crate have either a README or a doc.rs page??
     /// 2. If it has a doc.rs page, was the build successful?
     /// 3. If it was, does it contain any information about the doc coverage?
-    fn check_documentation(&self, cargo_crate: &Crate) -> CheckResult {
+    async fn check_documentation(&self, cargo_crate: &Crate) -> CheckResult {
         let maybe_doc_link = &cargo_crate
             .documentation
             .as_deref()
             .unwrap_or_else(|| cargo_crate.source_url.as_deref().unwrap());
         let mut status = Status::Green;
         let mut explanation = String::new();
-        let doc = DocService::new(cargo_crate.name.as_str(), maybe_doc_link);
+        let doc = DocService::new(cargo_crate.name.as_str(), maybe_doc_link, self.config.clone());
 
         log_if_verbose(
             self.verbose,
             format!("Crate doc is hosted on {}", doc.doc_source.to_string()).as_str(),
         );
 
-        if doc.check_doc_page_exists() {
+        if doc.check_doc_page_exists().await {
             match doc.doc_source {
-                DocSource::GithubReadMe => {
+                DocSource::RepoReadMe => {
                     log_if_verbose(self.verbose, "Crate has a README.");
-                    explanation.push_str("README exists. Can't guarantee the coverage.")
+                    explanation.push_str("README exists. Can't guarantee the coverage.");
+
+                    let source_url = cargo_crate.source_url.as_deref().unwrap().to_string();
+                    if let Ok(readme) = provider_for(&source_url, &self.config).file_contents("README.md").await
+                    {
+                        let links = crate::links::extract_links(&readme, &source_url);
+                        let broken_links = crate::links::find_broken_links(links).await;
+                        if !broken_links.is_empty() {
+                            status = Status::Red;
+                            let offenders = broken_links
+                                .iter()
+                                .map(|link| format!("{} ({})", link.url, link.status))
+                                .collect::<Vec<String>>()
+                                .join(", ");
+                            explanation.push_str(&format!(
+                                " Found {} dead link(s) in README.md: {}",
+                                broken_links.len(),
+                                offenders
+                            ));
+                        }
+                    }
                 }
                 DocSource::RustDoc => {
-                    if doc.has_successful_build() {
+                    if doc.has_successful_build().await {
                         log_if_verbose(
                             self.verbose,
                             "Crate has a doc.rs page. Will check build status and coverage",
                         );
-                        let doc_coverage_score = doc.get_rust_doc_coverage_score();
+                        let doc_coverage_score = doc.get_rust_doc_coverage_score().await;
                         match doc_coverage_score {
                             Ok(value) => {
                                 explanation.push_str(
                                     format!("{}% of the crate is documented", value).as_str(),
                                 );
+                                if let Some(build_metadata) = doc.build_metadata().await {
+                                    if let (Some(target), Some(rustc_version)) =
+                                        (build_metadata.target, build_metadata.rustc_version)
+                                    {
+                                        explanation.push_str(
+                                            format!(
+                                                " (built for {} with rustc {})",
+                                                target, rustc_version
+                                            )
+                                            .as_str(),
+                                        );
+                                    }
+                                }
 
                                 match value.cmp(&50) {
                                     Ordering::Less => {
@@ -383,24 +635,74 @@ impl CrateCheck {
 
         CheckResult {
             question: None,
+            crate_name: None,
+            introduced_by: None,
             status,
             explanation,
         }
     }
 
     ///To check the changelog, wmt checks the following :
-    /// 1. Does the crate source(github) have either a CHANGELOG.md or a release page with a changelog note?
-    fn check_changelog(&self, cargo_crate: &Crate) -> CheckResult {
+    /// 1. Does the crate source(GitHub, GitLab, or Gitea) have either a CHANGELOG.md or a release page with a changelog note?
+    /// 2. If it has a CHANGELOG.md, does it have an entry for the latest release, and are its links alive?
+    /// 3. Does the CHANGELOG.md's newest section match the release's own notes?
+    async fn check_changelog(&self, cargo_crate: &Crate) -> CheckResult {
         let mut status = Status::Green;
         let mut explanation = String::new();
 
         let source_url = cargo_crate.source_url.as_deref().unwrap().to_string();
-        let github_service = GithubService::new(source_url);
-        let release_changelog_exists = github_service.release_changelog_exists();
+        let provider = provider_for(&source_url, &self.config);
+
+        match provider.file_contents("CHANGELOG.md").await {
+            Ok(changelog) => {
+                explanation.push_str("The crate release has a CHANGELOG.md note");
 
-        match github_service.changelog_note_exists() {
-            true => explanation.push_str("The crate release has a CHANGELOG.md note"),
-            false => match release_changelog_exists {
+                if let Ok(release) = provider.latest_release().await {
+                    let headings = crate::links::extract_headings(&changelog);
+                    match release.tag_name.as_deref() {
+                        Some(tag) if headings.iter().any(|heading| heading.contains(tag)) => {
+                            explanation.push_str(&format!(" with an entry for {}", tag));
+                        }
+                        Some(tag) => {
+                            status = Status::Yellow;
+                            explanation.push_str(&format!(
+                                ", but it has no entry for the latest release ({})",
+                                tag
+                            ));
+                        }
+                        None => {}
+                    }
+
+                    if let (Some(section), Some(release_body)) =
+                        (crate::links::latest_section(&changelog), release.body)
+                    {
+                        let diff = crate::links::unified_diff(&section, &release_body);
+                        if !diff.is_empty() {
+                            explanation.push_str(&format!(
+                                ". The release notes differ from CHANGELOG.md:\n{}",
+                                diff
+                            ));
+                        }
+                    }
+                }
+
+                let links = crate::links::extract_links(&changelog, &source_url);
+                let broken_links = crate::links::find_broken_links(links).await;
+                if !broken_links.is_empty() {
+                    status = Status::Red;
+                    let offenders = broken_links
+                        .iter()
+                        .map(|link| format!("{} ({})", link.url, link.status))
+                        .collect::<Vec<String>>()
+                        .join(", ");
+                    explanation.push_str(&format!(
+                        ". Found {} dead link(s) in CHANGELOG.md: {}",
+                        broken_links.len(),
+                        offenders
+                    ));
+                }
+            }
+            Err(_) => match provider.release_notes().await {
                 Ok(_) => explanation.push_str("The crate has a release changelog"),
                 Err(_) => {
                     status = Status::Red;
@@ -411,82 +713,106 @@ impl CrateCheck {
 
         CheckResult {
             question: None,
+            crate_name: None,
+            introduced_by: None,
             status,
             explanation,
         }
     }
 
-    fn check_tests(&self, cargo_crate: &Crate) -> CheckResult {
+    async fn check_tests(&self, cargo_crate: &Crate) -> CheckResult {
         let source_url = cargo_crate.source_url.as_deref().unwrap().to_string();
-        let github_service = GithubService::new(source_url);
-
-        let test_files = github_service.get_test_files();
+        let provider = provider_for(&source_url, &self.config);
 
         let mut explanation = String::new();
         let mut status = Status::Green;
 
-        match test_files.is_empty() {
-            true => {
+        match provider.has_tests().await {
+            Ok(true) => explanation.push_str("Test files found. Can't guarantee coverage"),
+            Ok(false) => {
                 status = Status::Red;
                 explanation.push_str("No test files found");
             }
-            false => explanation.push_str("Test files found. Can't guarantee coverage"),
+            Err(_) => {
+                status = Status::Red;
+                explanation.push_str("Could not list the repository's files");
+            }
         }
 
         CheckResult {
             question: None,
+            crate_name: None,
+            introduced_by: None,
             status,
             explanation,
         }
     }
 
-    fn check_bug_response(&self, cargo_crate: &Crate) -> CheckResult {
+    ///To check maintainer responsiveness, wmt looks at the most recent issues
+    /// and, for each, finds the first comment authored by someone other than
+    /// the issue's opener. Issues with zero non-author activity (no
+    /// third-party comment, regardless of whether the issue was later
+    /// closed) are ignored rather than counted as a response — a
+    /// self-closed or abandoned issue says nothing about maintainer
+    /// responsiveness. The median of those delays is compared against
+    /// `MAINTAINER_RESPONSE_THRESHOLD_HOURS`.
+    async fn check_bug_response(&self, cargo_crate: &Crate) -> CheckResult {
         let source_url = cargo_crate.source_url.as_deref().unwrap().to_string();
+        if !is_github_source(&source_url) {
+            return self.no_support_result();
+        }
         let github_service = GithubService::new(source_url);
 
-        log_if_verbose(self.verbose, "Checking open bug");
+        log_if_verbose(self.verbose, "Checking maintainer responsiveness");
 
-        let open_bugs = github_service.get_bugs(State::Open).into_iter();
+        let issues = github_service
+            .get_recent_issues(MAINTAINER_RESPONSE_ISSUE_SAMPLE_SIZE)
+            .await;
 
-        let mut explanation = String::new();
-        let mut status = Status::Green;
+        let mut response_hours: Vec<i64> = Vec::new();
+        for issue in &issues {
+            let opener = issue.user.login.as_str();
+            let comments = github_service.get_issue_comments(issue.number).await;
 
-        match open_bugs.len() == 0 {
-            true => {
-                log_if_verbose(self.verbose, "No open bug found");
-                explanation.push_str("There are no open bugs");
+            let first_response = comments
+                .iter()
+                .find(|comment| comment.user.login != opener)
+                .map(|comment| comment.created_at);
+
+            if let Some(responded_at) = first_response {
+                response_hours.push((responded_at - issue.created_at).num_hours());
             }
-            false => {
-                log_if_verbose(self.verbose, "Checking comments on open bug");
-                let open_bugs_with_comments = open_bugs
-                    .filter(|bug| bug.comments > 1)
-                    .collect::<Vec<Issue>>();
-                match open_bugs_with_comments.is_empty() {
-                    true => {
-                        status = Status::Red;
-                        explanation.push_str(&format!(
-                            "There are {} open bugs with no response from the maintainer(s)",
-                            open_bugs_with_comments.len()
-                        ))
-                    }
-                    false => {
-                        log_if_verbose(self.verbose, "All open bugs have responses");
-                        status = Status::Green;
-                        explanation.push_str("The maintainer(s) have responded to all open bugs");
-                    }
+        }
+
+        let mut status = Status::Green;
+        let explanation = match median(&mut response_hours) {
+            Some(median_hours) => {
+                if median_hours > MAINTAINER_RESPONSE_THRESHOLD_HOURS {
+                    status = Status::Red;
                 }
+                format!(
+                    "The median first-response time across {} issue(s) is {} hour(s)",
+                    response_hours.len(),
+                    median_hours
+                )
             }
-        }
+            None => {
+                status = Status::Yellow;
+                "None of the recent issues have a maintainer response yet".to_string()
+            }
+        };
 
         CheckResult {
             question: None,
+            crate_name: None,
+            introduced_by: None,
             status,
             explanation,
         }
     }
 
-    fn check_runs_against_latest_language(&self, cargo_crate: &Crate) -> CheckResult {
-        let mut check_tests = self.check_tests(cargo_crate);
+    async fn check_runs_against_latest_language(&self, cargo_crate: &Crate) -> CheckResult {
+        let mut check_tests = self.check_tests(cargo_crate).await;
 
         if let Status::Green = check_tests.status {
             check_tests.explanation = format!(
@@ -498,14 +824,17 @@ impl CrateCheck {
         check_tests
     }
 
-    fn check_continuous_integration(&self, cargo_crate: &Crate) -> CheckResult {
+    async fn check_continuous_integration(&self, cargo_crate: &Crate) -> CheckResult {
         let source_url = cargo_crate.source_url.as_deref().unwrap().to_string();
+        if !is_github_source(&source_url) {
+            return self.no_support_result();
+        }
         let github_service = GithubService::new(source_url);
 
         let mut explanation = String::new();
         let mut status = Status::Green;
 
-        let workflows = github_service.get_workflows();
+        let workflows = github_service.get_workflows().await;
         let count = workflows.len();
 
         match count > 0 {
@@ -520,24 +849,31 @@ impl CrateCheck {
 
         CheckResult {
             question: None,
+            crate_name: None,
+            introduced_by: None,
             status,
             explanation,
         }
     }
 
-    fn check_ci_status(&self, cargo_crate: &Crate) -> CheckResult {
+    async fn check_ci_status(&self, cargo_crate: &Crate) -> CheckResult {
         let source_url = cargo_crate.source_url.as_deref().unwrap().to_string();
+        if !is_github_source(&source_url) {
+            return self.no_support_result();
+        }
         let github_service = GithubService::new(source_url);
 
         let mut explanation = String::new();
         let mut status = Status::Green;
 
-        let workflows = github_service.get_workflows().into_iter();
+        let workflows = github_service.get_workflows().await.into_iter();
         let count = workflows.len();
 
         match count > 0 {
             true => {
-                let failing_workflows_count = self.get_failing_workflows(github_service, workflows);
+                let failing_workflows_count = self
+                    .get_failing_workflows(&github_service, workflows)
+                    .await;
 
                 match failing_workflows_count > 0 {
                     true => explanation.push_str(&format!(
@@ -557,98 +893,170 @@ impl CrateCheck {
 
         CheckResult {
             question: None,
+            crate_name: None,
+            introduced_by: None,
             status,
             explanation,
         }
     }
 
-    fn get_failing_workflows(
+    /// Fetches each workflow's failing runs concurrently rather than one at
+    /// a time, since the requests are independent of one another.
+    async fn get_failing_workflows(
         &self,
-        github_service: GithubService,
+        github_service: &GithubService,
         workflows: IntoIter<WorkFlow>,
     ) -> u64 {
-        let mut failing_workflows = HashMap::new();
-        for workflow in workflows {
-            let failing_runs = github_service.get_workflow_runs(workflow.id.to_string());
-            failing_workflows.insert(workflow.id.to_string(), failing_runs.len() as u64);
-        }
+        let runs_per_workflow = join_all(
+            workflows.map(|workflow| github_service.get_workflow_runs(workflow.id.to_string())),
+        )
+        .await;
 
-        let fw = failing_workflows
+        runs_per_workflow
             .iter()
-            .filter(|&(_, value)| value > &0u64)
-            .into_iter()
-            .count();
-
-        fw as u64
+            .filter(|runs| !runs.is_empty())
+            .count() as u64
     }
 
-    fn check_usage(&self, cargo_crate: &Crate) -> CheckResult {
+    async fn check_usage(&self, cargo_crate: &Crate) -> CheckResult {
         let source_url = cargo_crate.source_url.as_deref().unwrap().to_string();
-        let github_service = GithubService::new(source_url);
+        let provider = provider_for(&source_url, &self.config);
 
         let mut explanation = String::new();
         let mut status = Status::Green;
 
-        let commits = github_service.get_latest_commits();
-
-        if commits.iter().peekable().peek().is_some() {
-            explanation.push_str("There have been commits this year");
-        } else {
-            status = Status::Red;
-            explanation.push_str("There have been no commits this year");
+        match provider.latest_commit_date().await {
+            Ok(_) => explanation.push_str("There have been commits this year"),
+            Err(_) => {
+                status = Status::Red;
+                explanation.push_str("There have been no commits this year");
+            }
         }
 
         CheckResult {
             question: None,
+            crate_name: None,
+            introduced_by: None,
             status,
             explanation,
         }
     }
 
-    fn check_latest_commits(&self, cargo_crate: &Crate) -> CheckResult {
+    async fn check_latest_commits(&self, cargo_crate: &Crate) -> CheckResult {
         let source_url = cargo_crate.source_url.as_deref().unwrap().to_string();
-        let github_service = GithubService::new(source_url);
+        let provider = provider_for(&source_url, &self.config);
 
         let mut status = Status::Green;
 
         let today = Utc::now();
-
-        let last_commit = github_service.get_latest_commits().unwrap();
-        let commit_date = last_commit.first().unwrap().commit.author.date;
-        let date_difference = (today - commit_date).num_days();
-
-        if date_difference < 365 {
-            status = Status::Red;
-        }
-
-        let explanation = format!("The last commit was {} day(s) ago", date_difference);
+        let explanation = match provider.latest_commit_date().await {
+            Ok(commit_date) => {
+                let date_difference = (today - commit_date).num_days();
+                if date_difference < 365 {
+                    status = Status::Red;
+                }
+                format!("The last commit was {} day(s) ago", date_difference)
+            }
+            Err(_) => {
+                status = Status::Red;
+                "Could not find any commits".to_string()
+            }
+        };
 
         CheckResult {
             question: None,
+            crate_name: None,
+            introduced_by: None,
             status,
             explanation,
         }
     }
 
-    fn check_latest_release(&self, cargo_crate: &Crate) -> CheckResult {
+    ///To check the latest release, wmt checks how recent it was and, when
+    /// the source is GitHub, how frequently the project releases: the
+    /// median gap between the last `RELEASE_CADENCE_SAMPLE_SIZE` releases,
+    /// compared against `RELEASE_CADENCE_THRESHOLD_DAYS`.
+    async fn check_latest_release(&self, cargo_crate: &Crate) -> CheckResult {
         let source_url = cargo_crate.source_url.as_deref().unwrap().to_string();
-        let github_service = GithubService::new(source_url);
+        let provider = provider_for(&source_url, &self.config);
         let mut status = Status::Green;
         let mut explanation = String::new();
 
-        let latest_release = github_service.get_latest_release();
+        let latest_release = provider.latest_release().await;
         match latest_release {
             Ok(release) => {
-                let today = Utc::now();
-                let date_difference = (today - release.created_at.unwrap()).num_days();
-                if date_difference > 365 {
-                    status = Status::Yellow;
-                    explanation.push_str("The last release was over a year ago");
+                // The host's release API is usually enough; only pay for a
+                // tarball download when it left us with nothing to resolve.
+                let checkout = if release.created_at.is_none() {
+                    match cargo_crate.version.as_ref().and_then(|version| version.remote.as_deref()) {
+                        Some(version) => source_churn::checkout_tarball(&cargo_crate.name, version)
+                            .await
+                            .ok(),
+                        None => None,
+                    }
                 } else {
-                    explanation.push_str(&format!(
-                        "The last release was {} day(s) ago",
-                        date_difference
-                    ));
+                    None
+                };
+                let release_date = ReleaseDate::resolve(
+                    release.created_at,
+                    checkout.as_ref().map(|checkout| checkout.path()),
+                );
+                match release_date.date {
+                    Some(date) => {
+                        let today = Utc::now();
+                        let date_difference = (today - date).num_days();
+                        if date_difference > self.config.stale_red_days {
+                            status = Status::Red;
+                            explanation.push_str(&format!(
+                                "The last release was {} day(s) ago, well past the {}-day threshold",
+                                date_difference, self.config.stale_red_days
+                            ));
+                        } else if date_difference > self.config.stale_yellow_days {
+                            status = Status::Yellow;
+                            explanation.push_str(&format!(
+                                "The last release was {} day(s) ago, past the {}-day threshold",
+                                date_difference, self.config.stale_yellow_days
+                            ));
+                        } else {
+                            explanation.push_str(&format!(
+                                "The last release was {} day(s) ago",
+                                date_difference
+                            ));
+                        }
+                    }
+                    None => {
+                        status = Status::Yellow;
+                        explanation.push_str(
+                            "Could not determine when the last release happened",
+                        );
+                    }
+                }
+
+                if source_url.contains("github.com") {
+                    let github_service = GithubService::new(source_url);
+                    let releases = github_service
+                        .get_releases(RELEASE_CADENCE_SAMPLE_SIZE)
+                        .await;
+                    let by_day = release_order::collapse_same_day(&releases);
+                    let mut gaps_in_days: Vec<i64> = by_day
+                        .windows(2)
+                        .filter_map(|pair| match (pair[0].created_at, pair[1].created_at) {
+                            (Some(older), Some(newer)) => Some((newer - older).num_days()),
+                            _ => None,
+                        })
+                        .collect();
+
+                    if let Some(median_gap) = median(&mut gaps_in_days) {
+                        explanation.push_str(&format!(
+                            ". Releases happen roughly every {} day(s)",
+                            median_gap
+                        ));
+                        if let Status::Green = status {
+                            if median_gap > RELEASE_CADENCE_THRESHOLD_DAYS {
+                                status = Status::Yellow;
+                            }
+                        }
+                    }
                 }
             }
             Err(_) => {
@@ -659,6 +1067,452 @@ impl CrateCheck {
 
         CheckResult {
             question: None,
+            crate_name: None,
+            introduced_by: None,
+            status,
+            explanation,
+        }
+    }
+
+    ///To check for dead links, wmt checks the following :
+    /// 1. Fetch the crate's README and collect every hyperlink in it.
+    /// 2. Issue a concurrent request for each link and flag any that come back 4xx/5xx or unreachable.
+    async fn check_dead_links(&self, cargo_crate: &Crate) -> CheckResult {
+        let source_url = cargo_crate.source_url.as_deref().unwrap().to_string();
+        let provider = provider_for(&source_url, &self.config);
+
+        let readme = match provider.file_contents("README.md").await {
+            Ok(contents) => contents,
+            Err(_) => {
+                return CheckResult {
+                    question: None,
+                    crate_name: None,
+                    introduced_by: None,
+                    status: Status::Grey,
+                    explanation: "Could not fetch the README to check for dead links"
+                        .to_string(),
+                };
+            }
+        };
+
+        let links = crate::links::extract_links(&readme, &source_url);
+        let broken_links = crate::links::find_broken_links(links).await;
+
+        let mut status = Status::Green;
+        let mut explanation = String::new();
+
+        match broken_links.is_empty() {
+            true => explanation.push_str("No dead links found in the README"),
+            false => {
+                status = Status::Red;
+                let offenders = broken_links
+                    .iter()
+                    .map(|link| format!("{} ({})", link.url, link.status))
+                    .collect::<Vec<String>>()
+                    .join(", ");
+                explanation.push_str(&format!(
+                    "Found {} dead link(s): {}",
+                    broken_links.len(),
+                    offenders
+                ));
+            }
+        }
+
+        CheckResult {
+            question: None,
+            crate_name: None,
+            introduced_by: None,
+            status,
+            explanation,
+        }
+    }
+
+    ///To check webhook delivery health, wmt checks the following :
+    /// 1. Does the repository have any configured webhooks?
+    /// 2. If so, was each hook's most recent delivery a successful (2xx),
+    ///    non-redelivered attempt?
+    async fn check_webhook_delivery_health(&self, cargo_crate: &Crate) -> CheckResult {
+        let source_url = cargo_crate.source_url.as_deref().unwrap().to_string();
+        if !is_github_source(&source_url) {
+            return self.no_support_result();
+        }
+        let github_service = GithubService::new(source_url);
+
+        let hooks = match github_service.get_hooks().await {
+            Ok(hooks) => hooks,
+            Err(_) => {
+                return CheckResult {
+                    question: None,
+                    crate_name: None,
+                    introduced_by: None,
+                    status: Status::Grey,
+                    explanation: "Could not list the repository's webhooks".to_string(),
+                };
+            }
+        };
+
+        if hooks.is_empty() {
+            return CheckResult {
+                question: None,
+                crate_name: None,
+                introduced_by: None,
+                status: Status::Green,
+                explanation: "The repository has no configured webhooks".to_string(),
+            };
+        }
+
+        let mut status = Status::Green;
+        let mut worst_deliveries = Vec::new();
+        for hook in &hooks {
+            let deliveries = github_service
+                .get_hook_deliveries(hook.id)
+                .await
+                .unwrap_or_default();
+
+            match deliveries.first() {
+                Some(delivery) => {
+                    let succeeded =
+                        (200..300).contains(&delivery.status_code) && !delivery.redelivery;
+                    if !succeeded {
+                        status = Status::Red;
+                    }
+                    worst_deliveries.push(format!(
+                        "hook {} last delivered {}{}",
+                        hook.id,
+                        delivery.status_code,
+                        if delivery.redelivery { " (redelivering)" } else { "" }
+                    ));
+                }
+                None => worst_deliveries.push(format!("hook {} has no deliveries yet", hook.id)),
+            }
+        }
+
+        CheckResult {
+            question: None,
+            crate_name: None,
+            introduced_by: None,
+            status,
+            explanation: worst_deliveries.join(", "),
+        }
+    }
+
+    ///To check for an outdated dependency, wmt compares the resolved pinned
+    /// version against crates.io's newest non-yanked release: Green when
+    /// current, Yellow when a minor/patch version behind, Red when a major
+    /// version behind. A minor bump on a pre-1.0 crate counts as major,
+    /// since semver treats 0.x minor bumps as breaking.
+    async fn check_outdated(&self, cargo_crate: &Crate) -> CheckResult {
+        let local_version = match cargo_crate
+            .version
+            .as_ref()
+            .and_then(|version| version.local.as_deref())
+        {
+            Some(version) if version != MISSING_FIELD_PLACEHOLDER => version,
+            _ => return self.no_support_result(),
+        };
+        let current = Version::from_version_text(local_version);
+
+        let crate_response = match CratesService::new().get_crate(&cargo_crate.name).await {
+            Ok(response) => response,
+            Err(_) => return self.no_support_result(),
+        };
+
+        let newest = crate_response
+            .versions
+            .iter()
+            .filter(|version| !version.yanked && !version.num.contains('-'))
+            .map(|version| Version::from_version_text(&version.num))
+            .max_by_key(|version| (version.major, version.minor, version.patch));
+
+        let newest = match newest {
+            Some(version) => version,
+            None => return self.no_support_result(),
+        };
+
+        let is_current =
+            (current.major, current.minor, current.patch) == (newest.major, newest.minor, newest.patch);
+        let is_breaking = if current.major == 0 {
+            newest.major > current.major || newest.minor > current.minor
+        } else {
+            newest.major > current.major
+        };
+
+        let (status, explanation) = if is_current {
+            (Status::Green, format!("Up to date at {}", current.id))
+        } else if is_breaking {
+            (Status::Red, format!("{} → {} (major)", current.id, newest.id))
+        } else {
+            (
+                Status::Yellow,
+                format!("{} → {} (minor/patch)", current.id, newest.id),
+            )
+        };
+
+        CheckResult {
+            question: None,
+            crate_name: None,
+            introduced_by: None,
+            status,
+            explanation,
+        }
+    }
+
+    /// Predicts the next expected release from the median gap between the
+    /// last `RELEASE_CADENCE_SAMPLE_SIZE` GitHub releases (`last_release +
+    /// median_gap`), and flags how far the project has drifted past it:
+    /// `Green` on schedule, `Yellow` overdue by up to ~2x the median gap,
+    /// `Red` beyond that or when there isn't enough history to establish
+    /// a cadence at all.
+    async fn check_release_cadence(&self, cargo_crate: &Crate) -> CheckResult {
+        let source_url = match cargo_crate.source_url.as_deref() {
+            Some(url) if is_github_source(url) => url.to_string(),
+            _ => return self.no_support_result(),
+        };
+
+        let github_service = GithubService::new(source_url);
+        let releases = github_service
+            .get_releases(RELEASE_CADENCE_SAMPLE_SIZE)
+            .await;
+
+        let dates: Vec<chrono::DateTime<Utc>> = release_order::collapse_same_day(&releases)
+            .iter()
+            .filter_map(|release| release.created_at)
+            .collect();
+
+        if dates.len() < 2 {
+            return CheckResult {
+                question: None,
+                crate_name: None,
+                introduced_by: None,
+                status: Status::Red,
+                explanation: "Not enough release history to establish a cadence".to_string(),
+            };
+        }
+
+        let mut gaps_in_days: Vec<i64> = dates
+            .windows(2)
+            .map(|pair| (pair[1] - pair[0]).num_days())
+            .collect();
+        let median_gap = median(&mut gaps_in_days).unwrap().max(1);
+
+        let last_release = *dates.last().unwrap();
+        let next_expected = last_release + chrono::Duration::days(median_gap);
+        let overdue_days = (Utc::now() - next_expected).num_days();
+
+        let (status, schedule_note) = if overdue_days <= 0 {
+            (Status::Green, "not overdue".to_string())
+        } else if overdue_days <= median_gap * 2 {
+            (Status::Yellow, format!("{} day(s) overdue", overdue_days))
+        } else {
+            (Status::Red, format!("{} day(s) overdue", overdue_days))
+        };
+
+        CheckResult {
+            question: None,
+            crate_name: None,
+            introduced_by: None,
+            status,
+            explanation: format!(
+                "Releases every ~{} day(s); next expected around {}; currently {}",
+                median_gap,
+                next_expected.format("%Y-%m-%d"),
+                schedule_note
+            ),
+        }
+    }
+
+    /// Quantifies how much source actually changed between the two most
+    /// recently published (non-yanked) versions with an rdiff-style
+    /// rolling-hash diff, flagging a near-zero churn ratio as a likely
+    /// no-op/metadata-only release.
+    async fn check_source_churn(&self, cargo_crate: &Crate) -> CheckResult {
+        let crate_response = match CratesService::new().get_crate(&cargo_crate.name).await {
+            Ok(response) => response,
+            Err(_) => return self.no_support_result(),
+        };
+
+        let mut versions: Vec<Version> = crate_response
+            .versions
+            .iter()
+            .filter(|version| !version.yanked && !version.num.contains('-'))
+            .map(|version| Version::from_version_text(&version.num))
+            .collect();
+        versions.sort_by_key(|version| (version.major, version.minor, version.patch));
+
+        let (older, newer) = match (versions.len() >= 2, versions.pop(), versions.pop()) {
+            (true, Some(newer), Some(older)) => (older, newer),
+            _ => return self.no_support_result(),
+        };
+
+        let report = match source_churn::churn_between(&cargo_crate.name, &older.id, &newer.id).await
+        {
+            Ok(report) => report,
+            Err(_) => {
+                return CheckResult {
+                    question: None,
+                    crate_name: None,
+                    introduced_by: None,
+                    status: Status::Grey,
+                    explanation: "Could not download release tarballs to measure source churn"
+                        .to_string(),
+                };
+            }
+        };
+
+        let ratio = report.churn_ratio();
+        let status = if ratio < CHURN_NEAR_ZERO_THRESHOLD {
+            Status::Yellow
+        } else {
+            Status::Green
+        };
+
+        let mut explanation = format!(
+            "{} → {}: {} byte(s) inserted, {} byte(s) deleted across {} file(s)",
+            older.id,
+            newer.id,
+            report.inserted_bytes,
+            report.deleted_bytes,
+            report.files.len()
+        );
+        if let Status::Yellow = status {
+            explanation.push_str(" (looks like a no-op release)");
+        }
+
+        CheckResult {
+            question: None,
+            crate_name: None,
+            introduced_by: None,
+            status,
+            explanation,
+        }
+    }
+
+    /// Checks how many of the crate's declared `package.metadata.docs.rs`
+    /// targets (e.g. `wasm32-wasi`) actually built successfully on docs.rs,
+    /// so a crate that advertises cross-platform support but fails to
+    /// document on some of it doesn't read as a clean pass.
+    async fn check_documentation_build_targets(&self, cargo_crate: &Crate) -> CheckResult {
+        let maybe_doc_link = &cargo_crate
+            .documentation
+            .as_deref()
+            .unwrap_or_else(|| cargo_crate.source_url.as_deref().unwrap());
+        let doc = DocService::new(cargo_crate.name.as_str(), maybe_doc_link, self.config.clone());
+
+        if !matches!(doc.doc_source, DocSource::RustDoc) {
+            return self.no_support_result();
+        }
+
+        match doc.target_build_report().await {
+            Some(report) => {
+                let status = if report.failed_targets.is_empty() {
+                    Status::Green
+                } else if report.successful > 0 {
+                    Status::Yellow
+                } else {
+                    Status::Red
+                };
+
+                let mut explanation = format!(
+                    "{} documentation that builds on {}/{} declared targets",
+                    QUESTION_EXPLANATION_SUFFIX, report.successful, report.declared
+                );
+                if !report.failed_targets.is_empty() {
+                    explanation.push_str(&format!(
+                        " (failing: {})",
+                        report.failed_targets.join(", ")
+                    ));
+                }
+
+                CheckResult {
+                    question: None,
+                    crate_name: None,
+                    introduced_by: None,
+                    status,
+                    explanation,
+                }
+            }
+            None => self.no_support_result(),
+        }
+    }
+
+    /// Looks past the single download-count gate in
+    /// `check_production_readiness` to the crate's full crates.io version
+    /// timeline: what fraction of releases were yanked, how often new
+    /// versions ship, whether the latest one has crossed
+    /// `MAX_DOWNLOAD_FOR_MINOR_VERSION`, and whether the project still
+    /// ships 0.x versions.
+    async fn check_version_health(&self, cargo_crate: &Crate) -> CheckResult {
+        let crate_response = match CratesService::new().get_crate(&cargo_crate.name).await {
+            Ok(response) => response,
+            Err(_) => return self.no_support_result(),
+        };
+
+        let total = crate_response.versions.len();
+        if total == 0 {
+            return self.no_support_result();
+        }
+
+        let yanked_count = crate_response
+            .versions
+            .iter()
+            .filter(|version| version.yanked)
+            .count();
+        let yanked_ratio = yanked_count as f64 / total as f64;
+
+        let mut non_yanked: Vec<_> = crate_response
+            .versions
+            .iter()
+            .filter(|version| !version.yanked)
+            .collect();
+        non_yanked.sort_by_key(|version| version.created_at);
+
+        let months_since_last_release = non_yanked
+            .last()
+            .map(|version| (Utc::now() - version.created_at).num_days() / 30);
+
+        let mut gaps_in_days: Vec<i64> = non_yanked
+            .windows(2)
+            .map(|pair| (pair[1].created_at - pair[0].created_at).num_days())
+            .collect();
+        let median_gap_days = median(&mut gaps_in_days);
+
+        let newest = non_yanked
+            .iter()
+            .filter(|version| !version.num.contains('-'))
+            .last();
+        let still_pre_1_0 = newest
+            .map(|version| Version::from_version_text(&version.num).major == 0)
+            .unwrap_or(false);
+        let crossed_download_threshold = newest
+            .map(|version| version.downloads >= MAX_DOWNLOAD_FOR_MINOR_VERSION)
+            .unwrap_or(false);
+
+        let status = if yanked_ratio > 0.25 {
+            Status::Red
+        } else if yanked_ratio > 0.1 || (still_pre_1_0 && !crossed_download_threshold) {
+            Status::Yellow
+        } else {
+            Status::Green
+        };
+
+        let mut explanation = format!(
+            "{} {} yanked release(s) out of {}",
+            QUESTION_EXPLANATION_SUFFIX, yanked_count, total
+        );
+        if let Some(months) = months_since_last_release {
+            explanation.push_str(&format!(" and no release in {} month(s)", months));
+        }
+        if let Some(gap) = median_gap_days {
+            explanation.push_str(&format!("; releases every ~{} day(s)", gap));
+        }
+        if still_pre_1_0 {
+            explanation.push_str("; still shipping 0.x versions");
+        }
+
+        CheckResult {
+            question: None,
+            crate_name: None,
+            introduced_by: None,
             status,
             explanation,
         }
@@ -0,0 +1,68 @@
+use std::collections::HashMap;
+use std::fs;
+
+use anyhow::Result;
+use serde::Deserialize;
+
+/// A user-supplied `wmt.toml` table tuning how the checks themselves
+/// behave: the staleness gradient used by the recency check, and which
+/// questions run at all.
+#[derive(Debug, Clone, Deserialize)]
+pub struct CheckConfig {
+    /// Days since the last release before it's considered stale (Yellow).
+    #[serde(default = "default_stale_yellow_days")]
+    pub stale_yellow_days: i64,
+    /// Days since the last release before it's considered badly stale (Red).
+    #[serde(default = "default_stale_red_days")]
+    pub stale_red_days: i64,
+    /// Per-question enable flags, keyed by question number (e.g. `"3"`).
+    /// Questions not listed default to enabled.
+    #[serde(default)]
+    pub enabled: HashMap<String, bool>,
+    /// Path to a question set replacing the bundled `QUESTIONS_PATH`, so a
+    /// user can add questions as data without touching this crate.
+    #[serde(default)]
+    pub questions_path: Option<String>,
+    /// Self-hosted GitLab instance hosts (exact match, e.g.
+    /// `"git.example.com"`), since a custom domain can't be told apart from
+    /// a GitHub/Gitea one just by looking at the URL.
+    #[serde(default)]
+    pub gitlab_hosts: Vec<String>,
+    /// Self-hosted Gitea instance hosts (exact match), for the same reason.
+    #[serde(default)]
+    pub gitea_hosts: Vec<String>,
+}
+
+fn default_stale_yellow_days() -> i64 {
+    365
+}
+
+fn default_stale_red_days() -> i64 {
+    730
+}
+
+impl Default for CheckConfig {
+    fn default() -> Self {
+        CheckConfig {
+            stale_yellow_days: default_stale_yellow_days(),
+            stale_red_days: default_stale_red_days(),
+            enabled: HashMap::new(),
+            questions_path: None,
+            gitlab_hosts: Vec::new(),
+            gitea_hosts: Vec::new(),
+        }
+    }
+}
+
+impl CheckConfig {
+    /// Reads a `wmt.toml`-style config from `path`.
+    pub fn from_file(path: &str) -> Result<Self> {
+        let contents = fs::read_to_string(path)?;
+        Ok(toml::from_str(&contents)?)
+    }
+
+    /// Whether `question_number` should run at all, per `self.enabled`.
+    pub fn is_enabled(&self, question_number: &str) -> bool {
+        self.enabled.get(question_number).copied().unwrap_or(true)
+    }
+}
@@ -0,0 +1,127 @@
+use std::collections::HashMap;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use crate::constants::CHURN_BLOCK_SIZE;
+
+const MOD_ADLER: u32 = 65521;
+
+#[derive(Debug, Clone, Copy)]
+struct BlockSignature {
+    offset: usize,
+    strong_hash: u64,
+}
+
+/// A classic Adler-32-style checksum, kept as its `a`/`b` halves (rather
+/// than the folded `u32`) so it can be slid forward by one byte at a time
+/// instead of recomputed over the whole window on every step.
+#[derive(Debug, Clone, Copy)]
+struct RollingChecksum {
+    a: u32,
+    b: u32,
+    len: u32,
+}
+
+impl RollingChecksum {
+    fn new(data: &[u8]) -> Self {
+        let mut a: u32 = 1;
+        let mut b: u32 = 0;
+        for &byte in data {
+            a = (a + byte as u32) % MOD_ADLER;
+            b = (b + a) % MOD_ADLER;
+        }
+        RollingChecksum {
+            a,
+            b,
+            len: data.len() as u32,
+        }
+    }
+
+    fn value(&self) -> u32 {
+        (self.b << 16) | self.a
+    }
+
+    /// Slides a same-length window forward by one byte: `outgoing` leaves
+    /// from the front, `incoming` enters at the back. O(1), unlike
+    /// recomputing [`RollingChecksum::new`] over the whole window again.
+    fn slide(&mut self, outgoing: u8, incoming: u8) {
+        let modulus = MOD_ADLER as i64;
+        let a_new = (self.a as i64 - outgoing as i64 + incoming as i64).rem_euclid(modulus) as u32;
+        let b_new = (self.b as i64 + a_new as i64 - 1 - self.len as i64 * outgoing as i64)
+            .rem_euclid(modulus) as u32;
+        self.a = a_new;
+        self.b = b_new;
+    }
+}
+
+/// Diffs `old` against `new` using an rdiff-style rolling-hash block
+/// match: `old` is split into fixed `CHURN_BLOCK_SIZE` blocks, each keyed
+/// by a fast rolling (Adler-style) checksum with a strong hash to confirm
+/// matches. A window is then slid over `new` byte by byte — incrementally,
+/// not recomputed from scratch at each offset — so a rolling hit confirmed
+/// by the strong hash emits a "copy" and jumps a full block ahead,
+/// otherwise the current byte is an "insert" and the window advances by
+/// one. Returns `(inserted_bytes, deleted_bytes)`.
+pub fn diff_bytes(old: &[u8], new: &[u8]) -> (usize, usize) {
+    let mut blocks: HashMap<u32, Vec<BlockSignature>> = HashMap::new();
+    for (index, chunk) in old.chunks(CHURN_BLOCK_SIZE).enumerate() {
+        blocks
+            .entry(RollingChecksum::new(chunk).value())
+            .or_default()
+            .push(BlockSignature {
+                offset: index * CHURN_BLOCK_SIZE,
+                strong_hash: strong_hash(chunk),
+            });
+    }
+
+    let mut matched_old_bytes = 0usize;
+    let mut inserted_bytes = 0usize;
+    let mut position = 0usize;
+
+    let window_end = |position: usize| (position + CHURN_BLOCK_SIZE).min(new.len());
+    let mut rolling = RollingChecksum::new(&new[position..window_end(position)]);
+
+    while position < new.len() {
+        let current_end = window_end(position);
+        let window = &new[position..current_end];
+
+        let hit = blocks.get(&rolling.value()).and_then(|candidates| {
+            let strong = strong_hash(window);
+            candidates
+                .iter()
+                .find(|candidate| candidate.strong_hash == strong)
+        });
+
+        match hit {
+            Some(_) => {
+                matched_old_bytes += window.len();
+                position += window.len();
+                if position < new.len() {
+                    rolling = RollingChecksum::new(&new[position..window_end(position)]);
+                }
+            }
+            None => {
+                let outgoing = window[0];
+                inserted_bytes += 1;
+                position += 1;
+                let next_end = window_end(position);
+                if position < new.len() && next_end - position == window.len() {
+                    rolling.slide(outgoing, new[next_end - 1]);
+                } else if position < new.len() {
+                    rolling = RollingChecksum::new(&new[position..next_end]);
+                }
+            }
+        }
+    }
+
+    let deleted_bytes = old.len().saturating_sub(matched_old_bytes);
+    (inserted_bytes, deleted_bytes)
+}
+
+/// A strong hash to confirm a rolling-checksum collision before trusting
+/// a block match.
+fn strong_hash(data: &[u8]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    data.hash(&mut hasher);
+    hasher.finish()
+}
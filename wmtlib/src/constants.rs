@@ -5,3 +5,34 @@ pub const MISSING_FIELD_PLACEHOLDER: &str = "N/A";
 pub const MAX_DOWNLOAD_FOR_MINOR_VERSION: u64 = 500;
 pub const QUESTION_EXPLANATION_SUFFIX: &str = "The project has";
 pub const RUST_DOC_URL: &str = "https://docs.rs";
+pub const DEFAULT_CHECK_CONCURRENCY: usize = 8;
+pub const MAINTAINER_RESPONSE_ISSUE_SAMPLE_SIZE: u8 = 30;
+pub const MAINTAINER_RESPONSE_THRESHOLD_HOURS: i64 = 72;
+pub const RELEASE_CADENCE_SAMPLE_SIZE: u8 = 10;
+pub const RELEASE_CADENCE_THRESHOLD_DAYS: i64 = 180;
+/// The minimum weighted score (see `scoring.rs`) a crate must clear when no
+/// `wmt.toml` overrides it.
+pub const DEFAULT_PASSING_THRESHOLD: f64 = 0.7;
+/// Exit code used when a weighted verdict falls below threshold, mirroring
+/// `cargo check`'s convention of returning 101 on failure.
+pub const THRESHOLD_FAILURE_EXIT_CODE: i32 = 101;
+/// Fixed block size (in bytes) used by the rdiff-style rolling-hash diff
+/// in `churn.rs`.
+pub const CHURN_BLOCK_SIZE: usize = 2048;
+/// A churn ratio (inserted + deleted bytes over old source size) below
+/// this is treated as a likely no-op/metadata-only release.
+pub const CHURN_NEAR_ZERO_THRESHOLD: f64 = 0.01;
+/// Default steady-state requests/second for `HTTPClient`'s rate limiter.
+pub const RATE_LIMIT_STEADY_PER_SECOND: f64 = 5.0;
+/// Token capacity of `HTTPClient`'s rate-limiter bucket: how many requests
+/// can go out back to back before the steady refill rate takes over.
+pub const RATE_LIMIT_BURST_CAPACITY: f64 = 10.0;
+/// How hard a `429` cuts the effective rate.
+pub const RATE_LIMIT_BACKOFF_FACTOR: f64 = 0.5;
+/// How much a successful response nudges the effective rate back toward
+/// the steady rate.
+pub const RATE_LIMIT_RECOVERY_FACTOR: f64 = 1.05;
+/// Floor for the effective rate, so repeated `429`s can't halve it to zero.
+pub const RATE_LIMIT_MIN_PER_SECOND: f64 = 0.1;
+/// Fallback backoff window when a `429` doesn't carry a `Retry-After`.
+pub const RATE_LIMIT_DEFAULT_RETRY_AFTER_SECONDS: u64 = 1;
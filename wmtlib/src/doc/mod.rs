@@ -4,21 +4,68 @@ use std::ops::Deref;
 
 use select::document::Document;
 use select::predicate::Class;
+use serde::{Deserialize, Serialize};
 
+use crate::check_config::CheckConfig;
 use crate::constants::RUST_DOC_URL;
-use crate::github::{GithubService, RepoMetrics};
-use crate::HTTPClient;
+use crate::provider::provider_for;
+use crate::{ConditionalResponse, HTTPClient};
+
+/// docs.rs's build-status JSON for a crate version, served at
+/// `/crate/{name}/{version}/status.json`.
+#[derive(Debug, Serialize, Deserialize)]
+struct DocsRsStatus {
+    doc_status: bool,
+    #[serde(default)]
+    rustc_version: Option<String>,
+    #[serde(default)]
+    target: Option<String>,
+    #[serde(default)]
+    doc_coverage: Option<DocsRsCoverage>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct DocsRsCoverage {
+    documented: u64,
+    total: u64,
+}
+
+/// One entry of docs.rs's per-target build list, served at
+/// `/crate/{name}/{version}/builds.json`, reflecting the outcome of
+/// building docs for a single `package.metadata.docs.rs.targets` entry.
+#[derive(Debug, Serialize, Deserialize)]
+struct DocsRsTargetBuild {
+    target: String,
+    build_status: bool,
+}
+
+/// The outcome of building documentation across every target a crate
+/// declares in `package.metadata.docs.rs.targets`.
+#[derive(Debug, Clone)]
+pub struct DocBuildTargets {
+    pub declared: usize,
+    pub successful: usize,
+    pub failed_targets: Vec<String>,
+}
+
+/// The build target + rustc version docs.rs built a crate's documentation
+/// with, surfaced in the check explanation.
+#[derive(Debug, Clone)]
+pub struct DocBuildMetadata {
+    pub target: Option<String>,
+    pub rustc_version: Option<String>,
+}
 
 pub enum DocSource {
-    GithubReadMe,
+    RepoReadMe,
     RustDoc,
 }
 
 impl Display for DocSource {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match *self {
-            DocSource::GithubReadMe => {
-                write!(f, "Github")
+            DocSource::RepoReadMe => {
+                write!(f, "Repository")
             }
             DocSource::RustDoc => {
                 write!(f, "Rust Doc")
@@ -32,16 +79,33 @@ pub struct DocService {
     pub doc_source: DocSource,
     pub doc_url: String,
     pub http_client: HTTPClient,
+    config: CheckConfig,
+}
+
+/// Hosts whose URLs we recognize well enough to fetch a README through a
+/// `RepoProvider` instead of falling back to the docs.rs page: GitHub,
+/// `gitlab.com`, and the self-hosted hosts `provider_for` is told about in
+/// `config`.
+fn is_known_repo_host(url: &str, config: &CheckConfig) -> bool {
+    let host = reqwest::Url::parse(url)
+        .ok()
+        .and_then(|url| url.host_str().map(str::to_string))
+        .unwrap_or_default();
+    url.contains("github.com")
+        || host == "gitlab.com"
+        || config.gitlab_hosts.contains(&host)
+        || config.gitea_hosts.contains(&host)
 }
 
 impl DocService {
-    pub fn new(crate_name: &str, crate_documentation: &str) -> Self {
-        match crate_documentation.contains("github.com") {
+    pub fn new(crate_name: &str, crate_documentation: &str, config: CheckConfig) -> Self {
+        match is_known_repo_host(crate_documentation, &config) {
             true => DocService {
                 crate_name: crate_name.to_string(),
-                doc_source: DocSource::GithubReadMe,
+                doc_source: DocSource::RepoReadMe,
                 doc_url: crate_documentation.to_string(),
                 http_client: HTTPClient::new(),
+                config,
             },
             false => {
                 let doc_url = format!("{}/{}", RUST_DOC_URL, crate_name);
@@ -50,23 +114,130 @@ impl DocService {
                     doc_source: DocSource::RustDoc,
                     doc_url,
                     http_client: HTTPClient::new(),
+                    config,
                 }
             }
         }
     }
 
-    pub fn has_successful_build(&self) -> bool {
-        let document = self.get_doc_page();
+    /// Fetches docs.rs's structured build-status JSON for the crate's latest
+    /// version. Returns `None` if the endpoint is unavailable, so callers can
+    /// fall back to scraping the rendered page. Cached on disk keyed by
+    /// crate name, revalidated with a conditional `If-None-Match` request
+    /// once the TTL lapses so a `304` just restarts the TTL window.
+    async fn fetch_status(&self) -> Option<DocsRsStatus> {
+        let cache_key = format!("{}:status", self.crate_name);
+        if let Some(cached) = crate::cache::get::<DocsRsStatus>("docs_rs", &cache_key) {
+            return Some(cached);
+        }
+
+        let status_url = format!("{}/crate/{}/latest/status.json", RUST_DOC_URL, self.crate_name);
+        let previous_etag = crate::cache::get_etag("docs_rs", &cache_key);
+        match self
+            .http_client
+            .get_json_conditional::<DocsRsStatus>(status_url, previous_etag)
+            .await
+            .ok()?
+        {
+            ConditionalResponse::NotModified => {
+                crate::cache::touch("docs_rs", &cache_key);
+                crate::cache::get_stale("docs_rs", &cache_key)
+            }
+            ConditionalResponse::Modified { value, etag } => {
+                crate::cache::set_with_etag("docs_rs", &cache_key, &value, etag.as_deref());
+                Some(value)
+            }
+        }
+    }
+
+    /// The build target and rustc version docs.rs used, when the status JSON
+    /// endpoint is available.
+    pub async fn build_metadata(&self) -> Option<DocBuildMetadata> {
+        self.fetch_status().await.map(|status| DocBuildMetadata {
+            target: status.target,
+            rustc_version: status.rustc_version,
+        })
+    }
+
+    /// Fetches docs.rs's per-target build list for the crate's latest
+    /// version. Returns `None` if the endpoint is unavailable; an empty list
+    /// from docs.rs is returned as `Some` of that empty list. Cached the
+    /// same way as `fetch_status`.
+    async fn fetch_target_builds(&self) -> Option<Vec<DocsRsTargetBuild>> {
+        let cache_key = format!("{}:builds", self.crate_name);
+        if let Some(cached) = crate::cache::get::<Vec<DocsRsTargetBuild>>("docs_rs", &cache_key) {
+            return Some(cached);
+        }
+
+        let builds_url = format!("{}/crate/{}/latest/builds.json", RUST_DOC_URL, self.crate_name);
+        let previous_etag = crate::cache::get_etag("docs_rs", &cache_key);
+        match self
+            .http_client
+            .get_json_conditional::<Vec<DocsRsTargetBuild>>(builds_url, previous_etag)
+            .await
+            .ok()?
+        {
+            ConditionalResponse::NotModified => {
+                crate::cache::touch("docs_rs", &cache_key);
+                crate::cache::get_stale("docs_rs", &cache_key)
+            }
+            ConditionalResponse::Modified { value, etag } => {
+                crate::cache::set_with_etag("docs_rs", &cache_key, &value, etag.as_deref());
+                Some(value)
+            }
+        }
+    }
+
+    /// Summarizes how many of the crate's declared `package.metadata.docs.rs`
+    /// targets actually built successfully, so a crate that advertises
+    /// cross-platform support but fails to document on some of it shows up
+    /// as a real quality signal rather than a single pass/fail bit.
+    pub async fn target_build_report(&self) -> Option<DocBuildTargets> {
+        let builds = self.fetch_target_builds().await?;
+        if builds.is_empty() {
+            return None;
+        }
+
+        let failed_targets: Vec<String> = builds
+            .iter()
+            .filter(|build| !build.build_status)
+            .map(|build| build.target.clone())
+            .collect();
+
+        Some(DocBuildTargets {
+            declared: builds.len(),
+            successful: builds.len() - failed_targets.len(),
+            failed_targets,
+        })
+    }
+
+    pub async fn has_successful_build(&self) -> bool {
+        if let Some(status) = self.fetch_status().await {
+            return status.doc_status;
+        }
+
+        let document = self.get_doc_page().await;
         document.select(Class("warning")).count() == 0
     }
 
-    fn get_doc_page(&self) -> Document {
-        let response = reqwest::blocking::get(&self.doc_url).unwrap();
-        Document::from_read(response).unwrap()
+    async fn get_doc_page(&self) -> Document {
+        let response = self.http_client.get(&self.doc_url).await.unwrap();
+        let body = response.text().await.unwrap();
+        Document::from(body.as_str())
     }
 
-    pub fn get_rust_doc_coverage_score(&self) -> Result<u64, ParseIntError> {
-        let document = self.get_doc_page();
+    pub async fn get_rust_doc_coverage_score(&self) -> Result<u64, ParseIntError> {
+        if let Some(DocsRsStatus {
+            doc_coverage: Some(coverage),
+            ..
+        }) = self.fetch_status().await
+        {
+            if coverage.total > 0 {
+                return Ok((coverage.documented * 100) / coverage.total);
+            }
+        }
+
+        let document = self.get_doc_page().await;
         let mut explanation = String::new();
         document
             .select(Class("pure-menu-link"))
@@ -85,16 +256,21 @@ impl DocService {
         result.replace("%", "")
     }
 
-    pub fn check_doc_page_exists(&self) -> bool {
+    pub async fn check_doc_page_exists(&self) -> bool {
         match self.doc_source {
-            DocSource::GithubReadMe => {
-                let github_service = GithubService::new(self.doc_url.to_string());
-                let repo_metric: RepoMetrics = github_service.get_repo_metrics().unwrap();
-                repo_metric.files.get("readme").is_some()
+            DocSource::RepoReadMe => {
+                let provider = provider_for(&self.doc_url, &self.config);
+                let readme_url = provider.file_url("README.md").await;
+                self.http_client
+                    .get(&readme_url)
+                    .await
+                    .map(|response| response.status().is_success())
+                    .unwrap_or(false)
             }
             DocSource::RustDoc => self
                 .http_client
                 .get(&self.doc_url)
+                .await
                 .unwrap()
                 .status()
                 .is_success(),
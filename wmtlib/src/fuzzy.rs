@@ -0,0 +1,42 @@
+/// Characters that mark a word boundary, e.g. in `owner/name` or
+/// `bug-report-response`. A match right after one of these (or at the very
+/// start of the candidate) is a stronger signal than a match mid-word.
+const BOUNDARY_CHARS: [char; 4] = ['/', '-', '_', ' '];
+
+/// Scores how well `query`'s characters appear, in order, inside
+/// `candidate` (case-insensitive). Returns `None` when `query` isn't a
+/// subsequence of `candidate` at all. Higher scores are better matches:
+/// boundary and consecutive-run matches are rewarded, gaps between matches
+/// are penalized.
+pub fn fuzzy_score(query: &str, candidate: &str) -> Option<i64> {
+    if query.is_empty() {
+        return Some(0);
+    }
+
+    let candidate_chars: Vec<char> = candidate.to_lowercase().chars().collect();
+    let mut score: i64 = 0;
+    let mut search_from = 0;
+    let mut last_match: Option<usize> = None;
+
+    for query_char in query.to_lowercase().chars() {
+        let match_index = (search_from..candidate_chars.len())
+            .find(|&index| candidate_chars[index] == query_char)?;
+
+        let is_boundary =
+            match_index == 0 || BOUNDARY_CHARS.contains(&candidate_chars[match_index - 1]);
+        if is_boundary {
+            score += 10;
+        }
+
+        match last_match {
+            Some(previous) if match_index == previous + 1 => score += 5,
+            Some(previous) => score -= (match_index - previous - 1) as i64,
+            None => {}
+        }
+
+        last_match = Some(match_index);
+        search_from = match_index + 1;
+    }
+
+    Some(score + 1)
+}
@@ -3,17 +3,17 @@ use std::env;
 
 use anyhow::{anyhow, Result};
 use chrono::{DateTime, Utc};
-use octocrab::models::issues::Issue;
+use octocrab::models::issues::{Comment, Issue};
 use octocrab::models::repos::{Content, Release};
 use octocrab::models::Repository;
 use octocrab::models::workflows::{Run, WorkFlow};
 use octocrab::Octocrab;
-use octocrab::params::State;
-use serde::Deserialize;
+use octocrab::params::{issues::Sort, Direction, State};
+use serde::{Deserialize, Serialize};
 
-use crate::HTTPClient;
+use crate::{ConditionalResponse, HTTPClient};
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct RepoMetrics {
     pub health_percentage: u64,
     pub description: Option<String>,
@@ -23,21 +23,34 @@ pub struct RepoMetrics {
     pub content_reports_enabled: Option<bool>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct Commit {
     pub author: CommitAuthor,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct CommitAuthor {
     pub date: DateTime<Utc>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct Commits {
     pub commit: Commit,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Hook {
+    pub id: u64,
+    pub active: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct HookDelivery {
+    pub id: u64,
+    pub status_code: u16,
+    pub redelivery: bool,
+}
+
 pub struct GithubService {
     pub url: String,
     pub repo: String,
@@ -71,66 +84,108 @@ impl GithubService {
         }
     }
 
-    pub fn get_repo_metrics(&self) -> reqwest::Result<RepoMetrics> {
-        self.http_client.get_json(format!(
+    pub async fn get_repo_metrics(&self) -> reqwest::Result<RepoMetrics> {
+        let cache_key = format!("{}/{}:community_profile", self.owner, self.repo);
+        if let Some(cached) = crate::cache::get::<RepoMetrics>("github", &cache_key) {
+            return Ok(cached);
+        }
+
+        let url = format!(
             "{}/community/profile",
             self.http_client
                 .build_github_api_url(&self.owner, &self.repo)
-        ))
+        );
+        let previous_etag = crate::cache::get_etag("github", &cache_key);
+        match self
+            .http_client
+            .get_json_conditional::<RepoMetrics>(url, previous_etag)
+            .await?
+        {
+            ConditionalResponse::NotModified => {
+                crate::cache::touch("github", &cache_key);
+                Ok(crate::cache::get_stale("github", &cache_key)
+                    .expect("a 304 implies a cached value exists"))
+            }
+            ConditionalResponse::Modified { value, etag } => {
+                crate::cache::set_with_etag("github", &cache_key, &value, etag.as_deref());
+                Ok(value)
+            }
+        }
     }
 
-    #[tokio::main(flavor = "current_thread")]
     pub async fn get_repo(&self) -> Repository {
-        return self
+        let cache_key = format!("{}/{}:repo", self.owner, self.repo);
+        if let Some(cached) = crate::cache::get::<Repository>("github", &cache_key) {
+            return cached;
+        }
+
+        let repo = self
             .github_client
             .repos(&self.owner, &self.repo)
             .get()
             .await
             .unwrap();
+        crate::cache::set("github", &cache_key, &repo);
+        repo
     }
 
-    pub fn build_file_url(&self, file: &str) -> String {
-        let default_branch = self.get_repo().default_branch.unwrap();
+    pub async fn build_file_url(&self, file: &str) -> String {
+        let default_branch = self.get_repo().await.default_branch.unwrap();
         format!("{}/blob/{}/{}", self.url, default_branch, file)
     }
 
-    #[tokio::main(flavor = "current_thread")]
     pub async fn get_latest_release(&self) -> octocrab::Result<Release> {
-        self.github_client
+        let cache_key = format!("{}/{}:latest_release", self.owner, self.repo);
+        if let Some(cached) = crate::cache::get::<Release>("github", &cache_key) {
+            return Ok(cached);
+        }
+
+        let release = self
+            .github_client
             .repos(&self.owner, &self.repo)
             .releases()
             .get_latest()
-            .await
+            .await?;
+        crate::cache::set("github", &cache_key, &release);
+        Ok(release)
     }
 
-    pub fn changelog_note_exists(&self) -> bool {
-        reqwest::blocking::get(&self.build_file_url("CHANGELOG.md"))
-            .unwrap()
-            .status()
-            .is_success()
+    pub async fn changelog_note_exists(&self) -> bool {
+        self.http_client
+            .get(&self.build_file_url("CHANGELOG.md").await)
+            .await
+            .map(|response| response.status().is_success())
+            .unwrap_or(false)
     }
 
-    pub fn release_changelog_exists(&self) -> Result<Option<String>> {
-        let latest_release = self.get_latest_release();
+    pub async fn release_changelog_exists(&self) -> Result<Option<String>> {
+        let latest_release = self.get_latest_release().await;
         match latest_release {
             Ok(release) => Ok(release.body),
             Err(_) => Err(anyhow!("Could not get changelog")),
         }
     }
 
-    #[tokio::main(flavor = "current_thread")]
     pub async fn get_repo_content(&self) -> Vec<Content> {
-        self.github_client
+        let cache_key = format!("{}/{}:repo_content", self.owner, self.repo);
+        if let Some(cached) = crate::cache::get::<Vec<Content>>("github", &cache_key) {
+            return cached;
+        }
+
+        let content = self
+            .github_client
             .repos(&self.owner, &self.repo)
             .get_content()
             .send()
             .await
             .unwrap()
-            .items
+            .items;
+        crate::cache::set("github", &cache_key, &content);
+        content
     }
 
-    pub fn get_test_files(&self) -> Vec<Content> {
-        let repo_content = self.get_repo_content();
+    pub async fn get_test_files(&self) -> Vec<Content> {
+        let repo_content = self.get_repo_content().await;
         let mut test_dir: Vec<Content> = Vec::new();
         for item in repo_content {
             if item.r#type == "dir" && item.path.contains("test") {
@@ -141,7 +196,6 @@ impl GithubService {
         test_dir
     }
 
-    #[tokio::main(flavor = "current_thread")]
     pub async fn get_bugs(&self, status: State) -> Vec<Issue> {
         self.github_client
             .issues(&self.owner, &self.repo)
@@ -154,7 +208,84 @@ impl GithubService {
             .items
     }
 
-    #[tokio::main(flavor = "current_thread")]
+    /// The most recent issues (open or closed), newest first, used to gauge
+    /// how quickly the maintainer(s) respond to reports.
+    pub async fn get_recent_issues(&self, limit: u8) -> Vec<Issue> {
+        self.github_client
+            .issues(&self.owner, &self.repo)
+            .list()
+            .state(State::All)
+            .sort(Sort::Created)
+            .direction(Direction::Descending)
+            .per_page(limit)
+            .send()
+            .await
+            .unwrap()
+            .items
+    }
+
+    pub async fn get_issue_comments(&self, issue_number: u64) -> Vec<Comment> {
+        self.github_client
+            .issues(&self.owner, &self.repo)
+            .list_comments(issue_number)
+            .send()
+            .await
+            .unwrap()
+            .items
+    }
+
+    /// The most recent releases, newest first, used to gauge release cadence.
+    pub async fn get_releases(&self, limit: u8) -> Vec<Release> {
+        let cache_key = format!("{}/{}:releases", self.owner, self.repo);
+        if let Some(cached) = crate::cache::get::<Vec<Release>>("github", &cache_key) {
+            return cached;
+        }
+
+        let releases = self
+            .github_client
+            .repos(&self.owner, &self.repo)
+            .releases()
+            .list()
+            .per_page(limit)
+            .send()
+            .await
+            .unwrap()
+            .items;
+        crate::cache::set("github", &cache_key, &releases);
+        releases
+    }
+
+    /// The repository's configured webhooks.
+    pub async fn get_hooks(&self) -> reqwest::Result<Vec<Hook>> {
+        let cache_key = format!("{}/{}:hooks", self.owner, self.repo);
+        if let Some(cached) = crate::cache::get::<Vec<Hook>>("github", &cache_key) {
+            return Ok(cached);
+        }
+
+        let hooks: Vec<Hook> = self
+            .http_client
+            .get_json(format!(
+                "{}/hooks",
+                self.http_client
+                    .build_github_api_url(&self.owner, &self.repo)
+            ))
+            .await?;
+        crate::cache::set("github", &cache_key, &hooks);
+        Ok(hooks)
+    }
+
+    /// A hook's recent delivery attempts, newest first.
+    pub async fn get_hook_deliveries(&self, hook_id: u64) -> reqwest::Result<Vec<HookDelivery>> {
+        self.http_client
+            .get_json(format!(
+                "{}/hooks/{}/deliveries",
+                self.http_client
+                    .build_github_api_url(&self.owner, &self.repo),
+                hook_id
+            ))
+            .await
+    }
+
     pub async fn get_workflows(&self) -> Vec<WorkFlow> {
         self.github_client
             .workflows(&self.owner, &self.repo)
@@ -165,7 +296,6 @@ impl GithubService {
             .items
     }
 
-    #[tokio::main(flavor = "current_thread")]
     pub async fn get_workflow_runs(&self, workflow_id: String) -> Vec<Run> {
         self.github_client
             .workflows(&self.owner, &self.repo)
@@ -181,11 +311,32 @@ impl GithubService {
             .items
     }
 
-    pub fn get_latest_commits(&self) -> reqwest::Result<Vec<Commits>> {
-        self.http_client.get_json(format!(
+    pub async fn get_latest_commits(&self) -> reqwest::Result<Vec<Commits>> {
+        let cache_key = format!("{}/{}:latest_commits", self.owner, self.repo);
+        if let Some(cached) = crate::cache::get::<Vec<Commits>>("github", &cache_key) {
+            return Ok(cached);
+        }
+
+        let url = format!(
             "{}/commits?since=2021-01-00T00:00:00Z&per_page=1&page=1",
             self.http_client
                 .build_github_api_url(&self.owner, &self.repo)
-        ))
+        );
+        let previous_etag = crate::cache::get_etag("github", &cache_key);
+        match self
+            .http_client
+            .get_json_conditional::<Vec<Commits>>(url, previous_etag)
+            .await?
+        {
+            ConditionalResponse::NotModified => {
+                crate::cache::touch("github", &cache_key);
+                Ok(crate::cache::get_stale("github", &cache_key)
+                    .expect("a 304 implies a cached value exists"))
+            }
+            ConditionalResponse::Modified { value, etag } => {
+                crate::cache::set_with_etag("github", &cache_key, &value, etag.as_deref());
+                Ok(value)
+            }
+        }
     }
 }
@@ -0,0 +1,81 @@
+use anyhow::Result;
+use serde::Serialize;
+
+use crate::cargo_crate::CratesService;
+use crate::check::{CheckResult, CrateCheck};
+use crate::version::Version;
+
+/// Registry facts about a single crate, gathered for `wmt info`.
+#[derive(Debug, Serialize)]
+pub struct CrateMetadata {
+    pub name: String,
+    pub description: Option<String>,
+    pub documentation: Option<String>,
+    pub repository: Option<String>,
+    pub homepage: Option<String>,
+    pub downloads: u64,
+    pub latest_version: String,
+    pub latest_stable_version: Option<String>,
+    pub is_latest_yanked: bool,
+    pub versions: Vec<String>,
+}
+
+/// The combined "should I depend on this?" report: registry metadata
+/// alongside the complete matrix of `CheckResult`s for the crate.
+#[derive(Debug, Serialize)]
+pub struct InfoReport {
+    pub metadata: CrateMetadata,
+    pub checks: Vec<CheckResult>,
+}
+
+/// Looks up `crate_name` on crates.io and runs the full check suite
+/// against it, combining both into one "should I depend on this?" report.
+pub fn info(crate_name: &str, verbose: bool, concurrency: Option<usize>) -> Result<InfoReport> {
+    let runtime = tokio::runtime::Runtime::new().expect("Could not start the tokio runtime");
+    let crate_response = runtime.block_on(CratesService::new().get_crate(crate_name))?;
+    let crate_data = crate_response.crate_data;
+
+    let latest_stable_version = crate_response
+        .versions
+        .iter()
+        .filter(|version| !version.yanked && !version.num.contains('-'))
+        .map(|version| Version::from_version_text(&version.num))
+        .max_by_key(|version| (version.major, version.minor, version.patch))
+        .map(|version| version.id);
+
+    let is_latest_yanked = crate_response
+        .versions
+        .iter()
+        .find(|version| version.num == crate_data.max_version)
+        .map(|version| version.yanked)
+        .unwrap_or(false);
+
+    let metadata = CrateMetadata {
+        name: crate_data.name,
+        description: crate_data.description,
+        documentation: crate_data.documentation,
+        repository: crate_data.repository,
+        homepage: crate_data.homepage,
+        downloads: crate_data.downloads,
+        latest_version: crate_data.max_version,
+        latest_stable_version,
+        is_latest_yanked,
+        versions: crate_response
+            .versions
+            .iter()
+            .map(|version| version.num.clone())
+            .collect(),
+    };
+
+    let crate_check = CrateCheck::new(
+        vec![crate_name.to_string()],
+        verbose,
+        concurrency,
+        false,
+        false,
+        None,
+    );
+    let checks = crate_check.run_checks(None).into_iter().flatten().collect();
+
+    Ok(InfoReport { metadata, checks })
+}
@@ -1,21 +1,48 @@
 use std::env;
+use std::sync::{Arc, OnceLock};
 
-use reqwest::{Client, Response};
-use reqwest::header::{AUTHORIZATION, HeaderMap};
+use reqwest::{Client, Response, StatusCode};
+use reqwest::header::{AUTHORIZATION, ETAG, HeaderMap, IF_NONE_MATCH};
 use serde::de::DeserializeOwned;
 
-pub use self::check::CrateCheck;
+use crate::constants::{RATE_LIMIT_BURST_CAPACITY, RATE_LIMIT_STEADY_PER_SECOND};
+use crate::rate_limit::RateLimiter;
+
+pub use self::add::{add_dependency, AddOutcome, AddRequest, AddThreshold};
+pub use self::cache::configure as configure_cache;
+pub use self::cargo_crate::DependencyKind;
+pub use self::check::{CheckResult, CrateCheck};
+pub use self::check_config::CheckConfig;
+pub use self::constants::THRESHOLD_FAILURE_EXIT_CODE;
+pub use self::fuzzy::fuzzy_score;
+pub use self::info::{info, CrateMetadata, InfoReport};
 pub use self::questions::{DeserializableQuestions, Question, Questions, read_questions_from_file};
-pub use self::result::CommandResult;
+pub use self::result::{display_info_result, display_workload_result, CommandResult};
+pub use self::scoring::{per_question_outcomes, score, QuestionOutcome, QuestionRule, ScoringConfig, Verdict};
+pub use self::workload::{run_workload, RepoReport, WorkloadReport};
 
+mod add;
+mod cache;
 mod cargo_crate;
 mod check;
+mod check_config;
+mod churn;
 mod constants;
 mod doc;
+mod fuzzy;
 mod github;
+mod info;
+mod links;
+mod provider;
 mod questions;
+mod rate_limit;
+mod release_date;
+mod release_order;
 mod result;
+mod scoring;
+mod source_churn;
 mod version;
+mod workload;
 
 pub fn log_if_verbose(verbose: bool, message: &str) {
     match verbose {
@@ -24,9 +51,23 @@ pub fn log_if_verbose(verbose: bool, message: &str) {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct HTTPClient {
     request_client: Client,
+    /// Shared process-wide (see `HTTPClient::new`) so a sustained run of
+    /// requests (e.g. a full dependency-tree sweep) actually accumulates
+    /// backoff state instead of every caller starting from a fresh bucket.
+    rate_limiter: Arc<RateLimiter>,
+}
+
+static SHARED_HTTP_CLIENT: OnceLock<HTTPClient> = OnceLock::new();
+
+/// The outcome of a conditional (`If-None-Match`) request.
+#[derive(Debug)]
+pub enum ConditionalResponse<T> {
+    /// The server confirmed the previously cached value is still current.
+    NotModified,
+    Modified { value: T, etag: Option<String> },
 }
 
 pub fn get_github_token() -> String {
@@ -43,7 +84,15 @@ impl Default for HTTPClient {
 }
 
 impl HTTPClient {
+    /// Returns the process-wide `HTTPClient`, building it on first use.
+    /// Cloning it is cheap: `reqwest::Client` is already reference-counted
+    /// internally, and the rate limiter is explicitly shared, so every
+    /// caller is really just holding a handle to the same instance.
     pub fn new() -> HTTPClient {
+        SHARED_HTTP_CLIENT.get_or_init(Self::build).clone()
+    }
+
+    fn build() -> HTTPClient {
         static APP_USER_AGENT: &str =
             concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
 
@@ -62,17 +111,55 @@ impl HTTPClient {
             }
             Err(_) => request_client.build().unwrap_or_default(),
         };
-        HTTPClient { request_client }
+        HTTPClient {
+            request_client,
+            rate_limiter: Arc::new(RateLimiter::new(
+                RATE_LIMIT_STEADY_PER_SECOND,
+                RATE_LIMIT_BURST_CAPACITY,
+            )),
+        }
     }
 
-    #[tokio::main(flavor = "current_thread")]
     pub async fn get(&self, url: &str) -> Option<Response> {
-        self.request_client.get(url).send().await.ok()
+        self.rate_limiter.acquire().await;
+        let response = self.request_client.get(url).send().await.ok()?;
+        self.rate_limiter.observe(&response);
+        Some(response)
     }
 
-    #[tokio::main(flavor = "current_thread")]
     pub async fn get_json<T: DeserializeOwned>(&self, url: String) -> reqwest::Result<T> {
-        self.request_client.get(url).send().await?.json::<T>().await
+        self.rate_limiter.acquire().await;
+        let response = self.request_client.get(url).send().await?;
+        self.rate_limiter.observe(&response);
+        response.json::<T>().await
+    }
+
+    /// Fetches `url`, sending `if_none_match` (a previously-seen `ETag`) so
+    /// the server can reply `304 Not Modified` when nothing changed.
+    pub async fn get_json_conditional<T: DeserializeOwned>(
+        &self,
+        url: String,
+        if_none_match: Option<String>,
+    ) -> reqwest::Result<ConditionalResponse<T>> {
+        let mut request = self.request_client.get(url);
+        if let Some(etag) = if_none_match {
+            request = request.header(IF_NONE_MATCH, etag);
+        }
+
+        self.rate_limiter.acquire().await;
+        let response = request.send().await?;
+        self.rate_limiter.observe(&response);
+        if response.status() == StatusCode::NOT_MODIFIED {
+            return Ok(ConditionalResponse::NotModified);
+        }
+
+        let etag = response
+            .headers()
+            .get(ETAG)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+        let value = response.json::<T>().await?;
+        Ok(ConditionalResponse::Modified { value, etag })
     }
 
     pub fn build_github_api_url(&self, owner: &str, repo: &str) -> String {
@@ -0,0 +1,190 @@
+use std::collections::HashSet;
+use std::time::Duration;
+
+use futures::stream::{self, StreamExt};
+use pulldown_cmark::{Event, Parser, Tag};
+use reqwest::redirect::Policy;
+use reqwest::{Client, StatusCode, Url};
+
+const LINK_CHECK_CONCURRENCY: usize = 10;
+const LINK_CHECK_TIMEOUT_SECS: u64 = 10;
+
+/// A link found in a crate's documentation that responded with an error
+/// status, or couldn't be reached at all.
+#[derive(Debug, Clone)]
+pub struct BrokenLink {
+    pub url: String,
+    pub status: String,
+}
+
+/// Collects the unique, checkable hyperlinks referenced in a markdown
+/// document, resolving relative links against `base_url` and skipping
+/// `mailto:`/anchor-only targets.
+pub fn extract_links(markdown: &str, base_url: &str) -> Vec<String> {
+    let mut links = HashSet::new();
+
+    for event in Parser::new(markdown) {
+        if let Event::Start(Tag::Link(_, destination, _)) = event {
+            let destination = destination.to_string();
+            if destination.starts_with('#') || destination.starts_with("mailto:") {
+                continue;
+            }
+
+            let resolved = match Url::parse(&destination) {
+                Ok(url) => url.to_string(),
+                Err(_) => match Url::parse(base_url).and_then(|base| base.join(&destination)) {
+                    Ok(url) => url.to_string(),
+                    Err(_) => continue,
+                },
+            };
+
+            links.insert(resolved);
+        }
+    }
+
+    links.into_iter().collect()
+}
+
+/// Issues concurrent `HEAD` requests (falling back to `GET` on a 405) for
+/// each link and returns the ones that came back broken.
+pub async fn find_broken_links(links: Vec<String>) -> Vec<BrokenLink> {
+    let client = Client::builder()
+        .redirect(Policy::limited(10))
+        .timeout(Duration::from_secs(LINK_CHECK_TIMEOUT_SECS))
+        .build()
+        .unwrap_or_default();
+
+    stream::iter(links)
+        .map(|url| {
+            let client = client.clone();
+            async move { check_link(&client, url).await }
+        })
+        .buffer_unordered(LINK_CHECK_CONCURRENCY)
+        .filter_map(|broken_link| async move { broken_link })
+        .collect()
+        .await
+}
+
+/// Collects the text of every Markdown heading, in document order.
+pub fn extract_headings(markdown: &str) -> Vec<String> {
+    let mut headings = Vec::new();
+    let mut current = String::new();
+    let mut in_heading = false;
+
+    for event in Parser::new(markdown) {
+        match event {
+            Event::Start(Tag::Heading(..)) => {
+                in_heading = true;
+                current.clear();
+            }
+            Event::End(Tag::Heading(..)) => {
+                in_heading = false;
+                headings.push(current.trim().to_string());
+            }
+            Event::Text(text) | Event::Code(text) if in_heading => current.push_str(&text),
+            _ => {}
+        }
+    }
+
+    headings
+}
+
+/// The text of the first (i.e. newest) section of a changelog, from its
+/// first heading up to (but not including) the next one.
+pub fn latest_section(markdown: &str) -> Option<String> {
+    let heading_starts: Vec<usize> = Parser::new(markdown)
+        .into_offset_iter()
+        .filter_map(|(event, range)| match event {
+            Event::Start(Tag::Heading(..)) => Some(range.start),
+            _ => None,
+        })
+        .collect();
+
+    let start = *heading_starts.first()?;
+    let end = heading_starts.get(1).copied().unwrap_or(markdown.len());
+    Some(markdown[start..end].trim().to_string())
+}
+
+/// A minimal line-based unified diff (`-` for lines only in `old`, `+` for
+/// lines only in `new`), built on the lines' longest common subsequence.
+pub fn unified_diff(old: &str, new: &str) -> String {
+    let old_lines: Vec<&str> = old.lines().collect();
+    let new_lines: Vec<&str> = new.lines().collect();
+    let lcs = longest_common_subsequence(&old_lines, &new_lines);
+
+    let mut output = String::new();
+    let (mut i, mut j, mut k) = (0, 0, 0);
+    while i < old_lines.len() || j < new_lines.len() {
+        if k < lcs.len()
+            && i < old_lines.len()
+            && j < new_lines.len()
+            && old_lines[i] == lcs[k]
+            && new_lines[j] == lcs[k]
+        {
+            i += 1;
+            j += 1;
+            k += 1;
+        } else if i < old_lines.len() && (k >= lcs.len() || old_lines[i] != lcs[k]) {
+            output.push_str(&format!("-{}\n", old_lines[i]));
+            i += 1;
+        } else {
+            output.push_str(&format!("+{}\n", new_lines[j]));
+            j += 1;
+        }
+    }
+
+    output
+}
+
+fn longest_common_subsequence<'a>(a: &[&'a str], b: &[&'a str]) -> Vec<&'a str> {
+    let (n, m) = (a.len(), b.len());
+    let mut table = vec![vec![0usize; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            table[i][j] = if a[i] == b[j] {
+                table[i + 1][j + 1] + 1
+            } else {
+                table[i + 1][j].max(table[i][j + 1])
+            };
+        }
+    }
+
+    let mut result = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < n && j < m {
+        if a[i] == b[j] {
+            result.push(a[i]);
+            i += 1;
+            j += 1;
+        } else if table[i + 1][j] >= table[i][j + 1] {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+
+    result
+}
+
+async fn check_link(client: &Client, url: String) -> Option<BrokenLink> {
+    let response = match client.head(&url).send().await {
+        Ok(response) if response.status() == StatusCode::METHOD_NOT_ALLOWED => {
+            client.get(&url).send().await
+        }
+        other => other,
+    };
+
+    match response {
+        Ok(response) if response.status().is_success() || response.status().is_redirection() => {
+            None
+        }
+        Ok(response) => Some(BrokenLink {
+            url,
+            status: response.status().to_string(),
+        }),
+        Err(error) => Some(BrokenLink {
+            url,
+            status: error.to_string(),
+        }),
+    }
+}
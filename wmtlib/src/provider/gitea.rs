@@ -0,0 +1,173 @@
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use reqwest::Url;
+use serde::Deserialize;
+
+use crate::provider::{ReleaseInfo, RepoProvider};
+use crate::HTTPClient;
+
+#[derive(Debug, Deserialize)]
+struct GiteaRepo {
+    default_branch: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GiteaRelease {
+    body: Option<String>,
+    created_at: Option<DateTime<Utc>>,
+    tag_name: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GiteaContentEntry {
+    #[serde(rename = "type")]
+    entry_type: String,
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GiteaCommit {
+    created: DateTime<Utc>,
+}
+
+/// `RepoProvider` backed by the Gitea REST v1 API, which self-hosted
+/// instances expose at `{scheme}://{host}/api/v1`.
+pub struct GiteaProvider {
+    url: String,
+    api_base: String,
+    owner: String,
+    repo: String,
+    http_client: HTTPClient,
+}
+
+impl GiteaProvider {
+    /// Builds a provider for `url`. An unparseable `url` (a crate's
+    /// `repository` field isn't guaranteed to be a clean URL) degrades to
+    /// an empty owner/repo rather than panicking — every API call below
+    /// then just fails its own `Result` the same way a 404 would.
+    pub fn new(url: String) -> Self {
+        let parsed = Url::parse(&url).ok();
+        let api_base = parsed
+            .as_ref()
+            .map(|parsed| {
+                format!(
+                    "{}://{}/api/v1",
+                    parsed.scheme(),
+                    parsed.host_str().unwrap_or_default()
+                )
+            })
+            .unwrap_or_default();
+        let segments: Vec<&str> = parsed
+            .as_ref()
+            .and_then(|parsed| parsed.path_segments())
+            .map(|segments| segments.collect())
+            .unwrap_or_default();
+
+        GiteaProvider {
+            owner: segments.first().unwrap_or(&"").to_string(),
+            repo: segments.get(1).unwrap_or(&"").to_string(),
+            url,
+            api_base,
+            http_client: HTTPClient::new(),
+        }
+    }
+}
+
+#[async_trait]
+impl RepoProvider for GiteaProvider {
+    async fn default_branch(&self) -> Result<String> {
+        let repo: GiteaRepo = self
+            .http_client
+            .get_json(format!(
+                "{}/repos/{}/{}",
+                self.api_base, self.owner, self.repo
+            ))
+            .await?;
+
+        repo.default_branch
+            .ok_or_else(|| anyhow!("The Gitea repository has no default branch"))
+    }
+
+    async fn file_url(&self, file: &str) -> String {
+        let branch = self
+            .default_branch()
+            .await
+            .unwrap_or_else(|_| "main".to_string());
+        format!("{}/raw/branch/{}/{}", self.url, branch, file)
+    }
+
+    async fn latest_release(&self) -> Result<ReleaseInfo> {
+        let release: GiteaRelease = self
+            .http_client
+            .get_json(format!(
+                "{}/repos/{}/{}/releases/latest",
+                self.api_base, self.owner, self.repo
+            ))
+            .await
+            .map_err(|_| anyhow!("The Gitea repository has no releases"))?;
+
+        Ok(ReleaseInfo {
+            body: release.body,
+            created_at: release.created_at,
+            tag_name: release.tag_name,
+        })
+    }
+
+    async fn changelog_exists(&self) -> bool {
+        let changelog_url = self.file_url("CHANGELOG.md").await;
+        self.http_client
+            .get(&changelog_url)
+            .await
+            .map(|response| response.status().is_success())
+            .unwrap_or(false)
+    }
+
+    async fn file_contents(&self, file: &str) -> Result<String> {
+        let raw_url = self.file_url(file).await;
+        let response = self
+            .http_client
+            .get(&raw_url)
+            .await
+            .ok_or_else(|| anyhow!("Could not fetch {}", file))?;
+
+        if !response.status().is_success() {
+            return Err(anyhow!("{} does not exist in the repository", file));
+        }
+
+        response
+            .text()
+            .await
+            .map_err(|_| anyhow!("Could not read {}", file))
+    }
+
+    async fn has_tests(&self) -> Result<bool> {
+        let entries: Vec<GiteaContentEntry> = self
+            .http_client
+            .get_json(format!(
+                "{}/repos/{}/{}/contents",
+                self.api_base, self.owner, self.repo
+            ))
+            .await?;
+
+        Ok(entries
+            .iter()
+            .any(|entry| entry.entry_type == "dir" && entry.name.contains("test")))
+    }
+
+    async fn latest_commit_date(&self) -> Result<DateTime<Utc>> {
+        let commits: Vec<GiteaCommit> = self
+            .http_client
+            .get_json(format!(
+                "{}/repos/{}/{}/commits?limit=1",
+                self.api_base, self.owner, self.repo
+            ))
+            .await?;
+
+        commits
+            .into_iter()
+            .next()
+            .map(|commit| commit.created)
+            .ok_or_else(|| anyhow!("The Gitea repository has no commits"))
+    }
+}
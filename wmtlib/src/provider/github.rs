@@ -0,0 +1,96 @@
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::github::GithubService;
+use crate::provider::{ReleaseInfo, RepoProvider};
+
+/// `RepoProvider` backed by the existing GitHub API integration.
+pub struct GitHubProvider {
+    service: GithubService,
+}
+
+impl GitHubProvider {
+    pub fn new(source_url: String) -> Self {
+        GitHubProvider {
+            service: GithubService::new(source_url),
+        }
+    }
+}
+
+#[async_trait]
+impl RepoProvider for GitHubProvider {
+    async fn default_branch(&self) -> Result<String> {
+        self.service
+            .get_repo()
+            .await
+            .default_branch
+            .ok_or_else(|| anyhow!("The repository has no default branch"))
+    }
+
+    async fn file_url(&self, file: &str) -> String {
+        self.service.build_file_url(file).await
+    }
+
+    async fn latest_release(&self) -> Result<ReleaseInfo> {
+        let release = self
+            .service
+            .get_latest_release()
+            .await
+            .map_err(|_| anyhow!("Could not get the latest release"))?;
+
+        Ok(ReleaseInfo {
+            body: release.body,
+            created_at: release.created_at,
+            tag_name: Some(release.tag_name),
+        })
+    }
+
+    async fn changelog_exists(&self) -> bool {
+        self.service.changelog_note_exists().await
+    }
+
+    async fn release_notes(&self) -> Result<Option<String>> {
+        self.service.release_changelog_exists().await
+    }
+
+    async fn file_contents(&self, file: &str) -> Result<String> {
+        let branch = self.default_branch().await?;
+        let raw_url = format!(
+            "https://raw.githubusercontent.com/{}/{}/{}/{}",
+            self.service.owner, self.service.repo, branch, file
+        );
+        let response = self
+            .service
+            .http_client
+            .get(&raw_url)
+            .await
+            .ok_or_else(|| anyhow!("Could not fetch {}", file))?;
+
+        if !response.status().is_success() {
+            return Err(anyhow!("{} does not exist in the repository", file));
+        }
+
+        response
+            .text()
+            .await
+            .map_err(|_| anyhow!("Could not read {}", file))
+    }
+
+    async fn has_tests(&self) -> Result<bool> {
+        Ok(!self.service.get_test_files().await.is_empty())
+    }
+
+    async fn latest_commit_date(&self) -> Result<DateTime<Utc>> {
+        let commits = self
+            .service
+            .get_latest_commits()
+            .await
+            .map_err(|_| anyhow!("Could not fetch the latest commits"))?;
+
+        commits
+            .first()
+            .map(|commit| commit.commit.author.date)
+            .ok_or_else(|| anyhow!("The repository has no commits"))
+    }
+}
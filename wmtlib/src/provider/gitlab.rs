@@ -0,0 +1,180 @@
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use reqwest::Url;
+use serde::Deserialize;
+
+use crate::provider::{ReleaseInfo, RepoProvider};
+use crate::HTTPClient;
+
+#[derive(Debug, Deserialize)]
+struct GitLabProject {
+    default_branch: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitLabRelease {
+    description: Option<String>,
+    released_at: Option<DateTime<Utc>>,
+    tag_name: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitLabTreeEntry {
+    #[serde(rename = "type")]
+    entry_type: String,
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitLabCommit {
+    created_at: DateTime<Utc>,
+}
+
+/// `RepoProvider` backed by the GitLab REST v4 API, which self-hosted
+/// instances expose at `{scheme}://{host}/api/v4` same as gitlab.com does.
+pub struct GitLabProvider {
+    url: String,
+    api_base: String,
+    project_path: String,
+    http_client: HTTPClient,
+}
+
+impl GitLabProvider {
+    pub fn new(url: String) -> Self {
+        let parsed = Url::parse(&url).ok();
+        let api_base = parsed
+            .as_ref()
+            .map(|parsed| {
+                format!(
+                    "{}://{}/api/v4",
+                    parsed.scheme(),
+                    parsed.host_str().unwrap_or_default()
+                )
+            })
+            .unwrap_or_default();
+        let project_path = parsed
+            .as_ref()
+            .and_then(|parsed| parsed.path_segments())
+            .map(|segments| segments.collect::<Vec<&str>>().join("/"))
+            .unwrap_or_default();
+
+        GitLabProvider {
+            url,
+            api_base,
+            project_path,
+            http_client: HTTPClient::new(),
+        }
+    }
+
+    fn encoded_project_id(&self) -> String {
+        self.project_path.replace('/', "%2F")
+    }
+}
+
+#[async_trait]
+impl RepoProvider for GitLabProvider {
+    async fn default_branch(&self) -> Result<String> {
+        let project: GitLabProject = self
+            .http_client
+            .get_json(format!(
+                "{}/projects/{}",
+                self.api_base,
+                self.encoded_project_id()
+            ))
+            .await?;
+
+        project
+            .default_branch
+            .ok_or_else(|| anyhow!("The GitLab project has no default branch"))
+    }
+
+    async fn file_url(&self, file: &str) -> String {
+        let branch = self
+            .default_branch()
+            .await
+            .unwrap_or_else(|_| "main".to_string());
+        format!("{}/-/blob/{}/{}", self.url, branch, file)
+    }
+
+    async fn latest_release(&self) -> Result<ReleaseInfo> {
+        let releases: Vec<GitLabRelease> = self
+            .http_client
+            .get_json(format!(
+                "{}/projects/{}/releases",
+                self.api_base,
+                self.encoded_project_id()
+            ))
+            .await?;
+
+        releases
+            .into_iter()
+            .next()
+            .map(|release| ReleaseInfo {
+                body: release.description,
+                created_at: release.released_at,
+                tag_name: release.tag_name,
+            })
+            .ok_or_else(|| anyhow!("The GitLab project has no releases"))
+    }
+
+    async fn changelog_exists(&self) -> bool {
+        let changelog_url = self.file_url("CHANGELOG.md").await;
+        self.http_client
+            .get(&changelog_url)
+            .await
+            .map(|response| response.status().is_success())
+            .unwrap_or(false)
+    }
+
+    async fn file_contents(&self, file: &str) -> Result<String> {
+        let branch = self.default_branch().await?;
+        let raw_url = format!("{}/-/raw/{}/{}", self.url, branch, file);
+        let response = self
+            .http_client
+            .get(&raw_url)
+            .await
+            .ok_or_else(|| anyhow!("Could not fetch {}", file))?;
+
+        if !response.status().is_success() {
+            return Err(anyhow!("{} does not exist in the repository", file));
+        }
+
+        response
+            .text()
+            .await
+            .map_err(|_| anyhow!("Could not read {}", file))
+    }
+
+    async fn has_tests(&self) -> Result<bool> {
+        let tree: Vec<GitLabTreeEntry> = self
+            .http_client
+            .get_json(format!(
+                "{}/projects/{}/repository/tree",
+                self.api_base,
+                self.encoded_project_id()
+            ))
+            .await?;
+
+        Ok(tree
+            .iter()
+            .any(|entry| entry.entry_type == "tree" && entry.name.contains("test")))
+    }
+
+    async fn latest_commit_date(&self) -> Result<DateTime<Utc>> {
+        let commits: Vec<GitLabCommit> = self
+            .http_client
+            .get_json(format!(
+                "{}/projects/{}/repository/commits?per_page=1",
+                self.api_base,
+                self.encoded_project_id()
+            ))
+            .await?;
+
+        commits
+            .into_iter()
+            .next()
+            .map(|commit| commit.created_at)
+            .ok_or_else(|| anyhow!("The GitLab project has no commits"))
+    }
+}
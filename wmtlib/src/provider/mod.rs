@@ -0,0 +1,66 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::check_config::CheckConfig;
+
+mod gitea;
+mod github;
+mod gitlab;
+
+pub use gitea::GiteaProvider;
+pub use github::GitHubProvider;
+pub use gitlab::GitLabProvider;
+
+/// A release as reported by a repo host, normalized across forges.
+#[derive(Debug, Clone)]
+pub struct ReleaseInfo {
+    pub body: Option<String>,
+    pub created_at: Option<DateTime<Utc>>,
+    pub tag_name: Option<String>,
+}
+
+/// Abstracts the handful of repo-host operations the checks need, so a
+/// crate's changelog/release/readme questions can be answered the same way
+/// whether its `repository` points at GitHub, GitLab, or a Gitea instance.
+#[async_trait]
+pub trait RepoProvider: Send + Sync {
+    async fn default_branch(&self) -> Result<String>;
+    async fn file_url(&self, file: &str) -> String;
+    async fn latest_release(&self) -> Result<ReleaseInfo>;
+    async fn changelog_exists(&self) -> bool;
+
+    /// Fetches the raw contents of a file from the repo's default branch.
+    async fn file_contents(&self, file: &str) -> Result<String>;
+
+    /// Whether the repository root has a directory that looks test-related.
+    async fn has_tests(&self) -> Result<bool>;
+
+    /// The timestamp of the most recent commit on the default branch.
+    async fn latest_commit_date(&self) -> Result<DateTime<Utc>>;
+
+    async fn release_notes(&self) -> Result<Option<String>> {
+        Ok(self.latest_release().await?.body)
+    }
+}
+
+/// Picks a `RepoProvider` by the host of a crate's `source_url`. The public
+/// `gitlab.com` host is recognized on sight; everything else — self-hosted
+/// GitLab or Gitea on a custom domain can't be told apart from a URL alone
+/// — has to be declared explicitly via `wmt.toml`'s `gitlab_hosts`/
+/// `gitea_hosts` (an exact host match, not a substring guess). Anything not
+/// matched falls back to `GitHubProvider`.
+pub fn provider_for(source_url: &str, config: &CheckConfig) -> Box<dyn RepoProvider> {
+    let host = reqwest::Url::parse(source_url)
+        .ok()
+        .and_then(|url| url.host_str().map(str::to_string))
+        .unwrap_or_default();
+
+    if host == "gitlab.com" || config.gitlab_hosts.iter().any(|known| known == &host) {
+        Box::new(GitLabProvider::new(source_url.to_string()))
+    } else if config.gitea_hosts.iter().any(|known| known == &host) {
+        Box::new(GiteaProvider::new(source_url.to_string()))
+    } else {
+        Box::new(GitHubProvider::new(source_url.to_string()))
+    }
+}
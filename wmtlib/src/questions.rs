@@ -19,6 +19,13 @@ pub enum CheckNames {
     Usage,
     LatestCommits,
     LatestRelease,
+    DeadLinks,
+    WebhookDeliveryHealth,
+    OutdatedDependency,
+    ReleaseCadence,
+    SourceChurn,
+    DocumentationBuildTargets,
+    VersionHealth,
 }
 
 /// Represents a question
@@ -42,6 +49,9 @@ impl Question {
 
 pub struct Questions {
     pub verbose: bool,
+    /// Overrides the bundled `QUESTIONS_PATH` with a user-supplied rule
+    /// set, so new questions can be added as data rather than code.
+    pub questions_path: Option<String>,
 }
 
 /// Helper struct for serde_json deserialize
@@ -60,17 +70,22 @@ pub fn read_questions_from_file(path: &str) -> Result<DeserializableQuestions, a
 
 impl Questions {
     pub fn new(verbose: bool) -> Self {
-        Questions { verbose }
+        Questions {
+            verbose,
+            questions_path: None,
+        }
     }
     /// Returns the available questions as numerical strings
     pub fn question_numbers() -> Vec<String> {
-        (1..13).map(|x| x.to_string()).collect()
+        (1..20).map(|x| x.to_string()).collect()
     }
 
-    /// Wrapper for `read_questions_from_file`
+    /// Wrapper for `read_questions_from_file`, reading from
+    /// `questions_path` when set instead of the bundled `QUESTIONS_PATH`.
     pub fn list(&self) -> DeserializableQuestions {
         log_if_verbose(self.verbose, "Getting available questions");
-        read_questions_from_file(QUESTIONS_PATH).unwrap()
+        let path = self.questions_path.as_deref().unwrap_or(QUESTIONS_PATH);
+        read_questions_from_file(path).unwrap()
     }
 
     pub fn show_results(&self, json: bool, data: Vec<Question>) {
@@ -97,4 +112,32 @@ impl Questions {
             .filter(|x| x.number == question_number)
             .collect::<Vec<Question>>()
     }
+
+    /// Finds the questions whose number or text best match a free-form
+    /// query, for callers that don't know the exact question number.
+    /// Results are ranked best match first.
+    pub fn fuzzy_describe(&self, query: &str, limit: usize) -> Vec<Question> {
+        let mut questions = self.list().questions;
+        log_if_verbose(
+            self.verbose,
+            format!("Fuzzy-matching questions against '{}'", query).as_str(),
+        );
+
+        let mut scored: Vec<(i64, usize)> = questions
+            .iter()
+            .enumerate()
+            .filter_map(|(index, question)| {
+                let label = format!("{} {}", question.number, question.question);
+                crate::fuzzy::fuzzy_score(query, &label).map(|score| (score, index))
+            })
+            .collect();
+        scored.sort_by(|a, b| b.0.cmp(&a.0));
+        scored.truncate(limit);
+
+        let mut by_index: Vec<Option<Question>> = questions.drain(..).map(Some).collect();
+        scored
+            .into_iter()
+            .filter_map(|(_, index)| by_index[index].take())
+            .collect()
+    }
 }
@@ -0,0 +1,107 @@
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use reqwest::header::RETRY_AFTER;
+use reqwest::{Response, StatusCode};
+
+use crate::constants::{
+    RATE_LIMIT_BACKOFF_FACTOR, RATE_LIMIT_DEFAULT_RETRY_AFTER_SECONDS, RATE_LIMIT_MIN_PER_SECOND,
+    RATE_LIMIT_RECOVERY_FACTOR,
+};
+
+#[derive(Debug)]
+struct BucketState {
+    tokens: f64,
+    last_refill: Instant,
+    effective_rate: f64,
+    parked_until: Option<Instant>,
+}
+
+/// A token-bucket rate limiter wrapping outbound HTTP requests: a steady
+/// refill rate plus a separate burst capacity, with backoff that halves
+/// the effective rate on a `429` and honors the response's `Retry-After`
+/// header, recovering gradually back toward the steady rate as requests
+/// succeed.
+#[derive(Debug)]
+pub struct RateLimiter {
+    steady_per_second: f64,
+    burst_capacity: f64,
+    state: Mutex<BucketState>,
+}
+
+impl RateLimiter {
+    pub fn new(steady_per_second: f64, burst_capacity: f64) -> Self {
+        RateLimiter {
+            steady_per_second,
+            burst_capacity,
+            state: Mutex::new(BucketState {
+                tokens: burst_capacity,
+                last_refill: Instant::now(),
+                effective_rate: steady_per_second,
+                parked_until: None,
+            }),
+        }
+    }
+
+    /// Blocks until a token is available, honoring any active backoff
+    /// window from a prior `429`.
+    pub async fn acquire(&self) {
+        loop {
+            let wait = {
+                let mut state = self.state.lock().unwrap();
+                let now = Instant::now();
+                let elapsed = now.duration_since(state.last_refill).as_secs_f64();
+                state.tokens = (state.tokens + elapsed * state.effective_rate).min(self.burst_capacity);
+                state.last_refill = now;
+
+                match state.parked_until {
+                    Some(parked_until) if now < parked_until => Some(parked_until - now),
+                    Some(_) => {
+                        // The backoff window is over; go round again to
+                        // take a token like any other request.
+                        state.parked_until = None;
+                        Some(Duration::ZERO)
+                    }
+                    None if state.tokens >= 1.0 => {
+                        state.tokens -= 1.0;
+                        None
+                    }
+                    None => {
+                        let deficit = 1.0 - state.tokens;
+                        Some(Duration::from_secs_f64(
+                            deficit / state.effective_rate.max(RATE_LIMIT_MIN_PER_SECOND),
+                        ))
+                    }
+                }
+            };
+
+            match wait {
+                Some(duration) => tokio::time::sleep(duration).await,
+                None => return,
+            }
+        }
+    }
+
+    /// Reacts to a response: on `429`, halves the effective rate and parks
+    /// until `Retry-After` elapses (falling back to a default backoff
+    /// window when the header is absent or unparseable); otherwise nudges
+    /// the effective rate back up toward the configured steady rate.
+    pub fn observe(&self, response: &Response) {
+        let mut state = self.state.lock().unwrap();
+        if response.status() == StatusCode::TOO_MANY_REQUESTS {
+            state.effective_rate = (state.effective_rate * RATE_LIMIT_BACKOFF_FACTOR)
+                .max(RATE_LIMIT_MIN_PER_SECOND);
+            let retry_after = response
+                .headers()
+                .get(RETRY_AFTER)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.parse::<u64>().ok())
+                .map(Duration::from_secs)
+                .unwrap_or_else(|| Duration::from_secs(RATE_LIMIT_DEFAULT_RETRY_AFTER_SECONDS));
+            state.parked_until = Some(Instant::now() + retry_after);
+        } else {
+            state.effective_rate =
+                (state.effective_rate * RATE_LIMIT_RECOVERY_FACTOR).min(self.steady_per_second);
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use std::fs;
+use std::path::Path;
+
+use chrono::{DateTime, TimeZone, Utc};
+
+/// crates.io tarballs occasionally carry zeroed/bogus file times; any
+/// resolved time before this is treated as missing rather than trusted.
+const SANITY_EPOCH_SECONDS: i64 = 946_684_800; // 2000-01-01T00:00:00Z
+
+/// Where a resolved release date came from, in order of preference.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReleaseDateSource {
+    Registry,
+    GitCommit,
+    ManifestMtime,
+    Unknown,
+}
+
+/// A release date resolved through a fallback chain, along with where it
+/// came from so callers can judge how much to trust it.
+#[derive(Debug)]
+pub struct ReleaseDate {
+    pub date: Option<DateTime<Utc>>,
+    pub source: ReleaseDateSource,
+}
+
+impl ReleaseDate {
+    /// Resolves a release date, preferring the registry's `created_at`,
+    /// then the last git commit time at `local_checkout` (if one is
+    /// available), then that checkout's `Cargo.toml` mtime, and finally an
+    /// explicit "unknown" state rather than treating an absent date as an
+    /// outright failure.
+    pub fn resolve(
+        registry_created_at: Option<DateTime<Utc>>,
+        local_checkout: Option<&Path>,
+    ) -> Self {
+        if let Some(date) = registry_created_at {
+            return ReleaseDate {
+                date: Some(date),
+                source: ReleaseDateSource::Registry,
+            };
+        }
+
+        if let Some(checkout) = local_checkout {
+            if let Some(date) = last_git_commit_time(checkout) {
+                return ReleaseDate {
+                    date: Some(date),
+                    source: ReleaseDateSource::GitCommit,
+                };
+            }
+
+            if let Some(date) = manifest_mtime(checkout) {
+                return ReleaseDate {
+                    date: Some(date),
+                    source: ReleaseDateSource::ManifestMtime,
+                };
+            }
+        }
+
+        ReleaseDate {
+            date: None,
+            source: ReleaseDateSource::Unknown,
+        }
+    }
+}
+
+fn last_git_commit_time(checkout: &Path) -> Option<DateTime<Utc>> {
+    let repo = git2::Repository::discover(checkout).ok()?;
+    let head = repo.head().ok()?;
+    let commit = head.peel_to_commit().ok()?;
+    sane_date(commit.time().seconds())
+}
+
+fn manifest_mtime(checkout: &Path) -> Option<DateTime<Utc>> {
+    let metadata = fs::metadata(checkout.join("Cargo.toml")).ok()?;
+    let modified = metadata.modified().ok()?;
+    let date: DateTime<Utc> = modified.into();
+    sane_date(date.timestamp())
+}
+
+fn sane_date(timestamp_seconds: i64) -> Option<DateTime<Utc>> {
+    if timestamp_seconds < SANITY_EPOCH_SECONDS {
+        return None;
+    }
+    Utc.timestamp_opt(timestamp_seconds, 0).single()
+}
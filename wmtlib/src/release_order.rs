@@ -0,0 +1,46 @@
+use std::collections::BTreeMap;
+
+use chrono::NaiveDate;
+use octocrab::models::repos::Release;
+
+/// Collapses `releases` down to one per calendar day — the highest
+/// version published that day, by tag name — sorted chronologically.
+/// Several releases on the same day otherwise show up as zero-day gaps
+/// and let the API's return order (rather than semver) decide which one
+/// is "latest".
+pub fn collapse_same_day(releases: &[Release]) -> Vec<&Release> {
+    let mut by_day: BTreeMap<NaiveDate, &Release> = BTreeMap::new();
+
+    for release in releases {
+        let created_at = match release.created_at {
+            Some(created_at) => created_at,
+            None => continue,
+        };
+        let day = created_at.date_naive();
+
+        by_day
+            .entry(day)
+            .and_modify(|existing| {
+                if tag_version_key(&release.tag_name) > tag_version_key(&existing.tag_name) {
+                    *existing = release;
+                }
+            })
+            .or_insert(release);
+    }
+
+    by_day.into_values().collect()
+}
+
+/// A forgiving `(major, minor, patch)` ordering key for a release tag
+/// name (e.g. `v1.2.3`, `1.2.3-rc.1`). Unparsable components default to
+/// `0` rather than panicking, since tag names aren't guaranteed semver.
+fn tag_version_key(tag_name: &str) -> (u64, u64, u64) {
+    let trimmed = tag_name.trim_start_matches(['v', 'V']);
+    let core = trimmed.split(['-', '+']).next().unwrap_or(trimmed);
+    let mut parts = core.split('.').map(|part| part.parse::<u64>().unwrap_or(0));
+    (
+        parts.next().unwrap_or(0),
+        parts.next().unwrap_or(0),
+        parts.next().unwrap_or(0),
+    )
+}
@@ -1,8 +1,11 @@
 use crate::check::CheckResult;
+use crate::info::InfoReport;
+use crate::workload::WorkloadReport;
 use crate::Question;
 use comfy_table::modifiers::UTF8_ROUND_CORNERS;
 use comfy_table::presets::UTF8_FULL;
 use comfy_table::{Cells, ContentArrangement, Table};
+use serde_json::json;
 
 #[derive(Debug)]
 pub struct CommandResult {
@@ -77,6 +80,7 @@ impl TableResult {
 
     pub fn from_checks(data: Vec<Vec<CheckResult>>) -> TableResult {
         let headers = vec![
+            String::from("Crate"),
             String::from("Question"),
             String::from("Status"),
             String::from("Explanation"),
@@ -97,3 +101,178 @@ impl TableResult {
         println!("{}", self.table)
     }
 }
+
+/// Renders check results as a Markdown table, for pasting into a PR
+/// description or CI job summary.
+pub fn render_markdown(data: &[Vec<CheckResult>]) -> String {
+    let mut output = String::from("| Crate | Question | Status | Explanation |\n|---|---|---|---|\n");
+    for entry in data {
+        for result in entry {
+            let row = result.to_vec();
+            output.push_str(&format!(
+                "| {} | {} | {} | {} |\n",
+                row[0], row[1], row[2], row[3]
+            ));
+        }
+    }
+    output
+}
+
+/// Renders check results as a SARIF 2.1.0 log, so CI tooling (e.g. GitHub
+/// code scanning) can ingest `wmt check` output directly.
+pub fn render_sarif(data: &[Vec<CheckResult>]) -> String {
+    let results: Vec<serde_json::Value> = data
+        .iter()
+        .flatten()
+        .map(|result| {
+            let row = result.to_vec();
+            json!({
+                "ruleId": row[1],
+                "level": sarif_level(&row[2]),
+                "message": { "text": format!("[{}] {}", row[0], row[3]) },
+            })
+        })
+        .collect();
+
+    let sarif = json!({
+        "version": "2.1.0",
+        "$schema": "https://raw.githubusercontent.com/oasis-tcs/sarif-spec/main/Schemata/sarif-schema-2.1.0.json",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "wmt",
+                    "informationUri": "https://github.com/olamyy/wmt",
+                    "version": env!("CARGO_PKG_VERSION"),
+                }
+            },
+            "results": results,
+        }],
+    });
+
+    serde_json::to_string_pretty(&sarif).unwrap_or_default()
+}
+
+/// Shows a workload's comparative report in the given `format` ("table",
+/// "json", or "markdown" — anything else falls back to "table").
+pub fn display_workload_result(format: &str, report: &WorkloadReport) {
+    match format {
+        "json" => println!("{}", serde_json::to_string_pretty(report).unwrap()),
+        "markdown" => println!("{}", render_workload_markdown(report)),
+        _ => {
+            let mut table = Table::new();
+            table
+                .set_header(vec!["Repo", "Health %"])
+                .load_preset(UTF8_FULL)
+                .apply_modifier(UTF8_ROUND_CORNERS)
+                .set_content_arrangement(ContentArrangement::Dynamic)
+                .set_table_width(150)
+                .trim_fmt();
+
+            for repo in &report.repos {
+                table.add_row(vec![
+                    repo.repo.clone(),
+                    format!("{:.1}", repo.health_percentage),
+                ]);
+            }
+
+            println!("{}", table)
+        }
+    }
+}
+
+fn render_workload_markdown(report: &WorkloadReport) -> String {
+    let mut output = format!("# {}\n\n| Repo | Health % |\n|---|---|\n", report.name);
+    for repo in &report.repos {
+        output.push_str(&format!(
+            "| {} | {:.1} |\n",
+            repo.repo, repo.health_percentage
+        ));
+    }
+    output
+}
+
+/// Shows a crate's combined registry metadata and check matrix in the
+/// given `format` ("table", "json", or "markdown" — anything else falls
+/// back to "table").
+pub fn display_info_result(format: &str, report: &InfoReport) {
+    match format {
+        "json" => println!("{}", serde_json::to_string_pretty(report).unwrap()),
+        "markdown" => println!("{}", render_info_markdown(report)),
+        _ => {
+            let metadata = &report.metadata;
+            let mut metadata_table = Table::new();
+            metadata_table
+                .load_preset(UTF8_FULL)
+                .apply_modifier(UTF8_ROUND_CORNERS)
+                .set_content_arrangement(ContentArrangement::Dynamic)
+                .set_table_width(150)
+                .trim_fmt();
+            for (field, value) in metadata_rows(metadata) {
+                metadata_table.add_row(vec![field, value]);
+            }
+            println!("{}", metadata_table);
+
+            let checks_table = TableResult::from_checks(vec![report.checks.clone()]);
+            checks_table.show();
+        }
+    }
+}
+
+fn metadata_rows(metadata: &crate::info::CrateMetadata) -> Vec<(String, String)> {
+    vec![
+        ("Name".to_string(), metadata.name.clone()),
+        (
+            "Description".to_string(),
+            metadata.description.clone().unwrap_or_default(),
+        ),
+        ("Downloads".to_string(), metadata.downloads.to_string()),
+        ("Latest version".to_string(), metadata.latest_version.clone()),
+        (
+            "Latest stable version".to_string(),
+            metadata.latest_stable_version.clone().unwrap_or_default(),
+        ),
+        (
+            "Latest version yanked".to_string(),
+            metadata.is_latest_yanked.to_string(),
+        ),
+        (
+            "Documentation".to_string(),
+            metadata.documentation.clone().unwrap_or_default(),
+        ),
+        (
+            "Repository".to_string(),
+            metadata.repository.clone().unwrap_or_default(),
+        ),
+        (
+            "Homepage".to_string(),
+            metadata.homepage.clone().unwrap_or_default(),
+        ),
+        ("Versions".to_string(), metadata.versions.len().to_string()),
+    ]
+}
+
+fn render_info_markdown(report: &InfoReport) -> String {
+    let metadata = &report.metadata;
+    let mut output = format!("# {}\n\n", metadata.name);
+    for (field, value) in metadata_rows(metadata) {
+        output.push_str(&format!("- **{}**: {}\n", field, value));
+    }
+    output.push_str("\n| Crate | Question | Status | Explanation |\n|---|---|---|---|\n");
+    for result in &report.checks {
+        let row = result.to_vec();
+        output.push_str(&format!(
+            "| {} | {} | {} | {} |\n",
+            row[0], row[1], row[2], row[3]
+        ));
+    }
+    output
+}
+
+fn sarif_level(status: &str) -> &'static str {
+    match status {
+        "RED" => "error",
+        "Yellow" => "warning",
+        "GREY" => "note",
+        _ => "none",
+    }
+}
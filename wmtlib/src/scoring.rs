@@ -0,0 +1,166 @@
+use std::collections::HashMap;
+use std::fs;
+
+use anyhow::Result;
+use serde::Deserialize;
+
+use crate::check::CheckResult;
+use crate::constants::DEFAULT_PASSING_THRESHOLD;
+
+/// A single question's entry in a `wmt.toml` rule set: how much it counts
+/// toward the aggregate score, and where a Green/Yellow/Red result lands
+/// as an explicit pass/warn/fail verdict.
+#[derive(Debug, Deserialize, Clone)]
+pub struct QuestionRule {
+    #[serde(default = "default_weight")]
+    pub weight: f64,
+    #[serde(default = "default_threshold")]
+    pub pass_threshold: f64,
+    #[serde(default = "default_warn_threshold")]
+    pub warn_threshold: f64,
+}
+
+impl QuestionRule {
+    fn outcome_for(&self, check_score: f64) -> QuestionOutcome {
+        if check_score >= self.pass_threshold {
+            QuestionOutcome::Pass
+        } else if check_score >= self.warn_threshold {
+            QuestionOutcome::Warn
+        } else {
+            QuestionOutcome::Fail
+        }
+    }
+}
+
+impl Default for QuestionRule {
+    fn default() -> Self {
+        QuestionRule {
+            weight: default_weight(),
+            pass_threshold: default_threshold(),
+            warn_threshold: default_warn_threshold(),
+        }
+    }
+}
+
+fn default_weight() -> f64 {
+    1.0
+}
+
+fn default_threshold() -> f64 {
+    DEFAULT_PASSING_THRESHOLD
+}
+
+fn default_warn_threshold() -> f64 {
+    0.5
+}
+
+/// A user-supplied `wmt.toml` replacing the built-in question weighting
+/// with a pluggable rule set: each question can carry its own weight and
+/// pass/warn/fail thresholds, keyed by question number (e.g. `"3"`).
+/// Questions not listed fall back to `QuestionRule::default()`.
+#[derive(Debug, Deserialize)]
+pub struct ScoringConfig {
+    #[serde(default)]
+    pub rules: HashMap<String, QuestionRule>,
+    /// The minimum weighted score (0.0-1.0) a crate must clear.
+    #[serde(default = "default_threshold")]
+    pub threshold: f64,
+}
+
+impl Default for ScoringConfig {
+    fn default() -> Self {
+        ScoringConfig {
+            rules: HashMap::new(),
+            threshold: DEFAULT_PASSING_THRESHOLD,
+        }
+    }
+}
+
+impl ScoringConfig {
+    /// Reads a `wmt.toml`-style config from `path`.
+    pub fn from_file(path: &str) -> Result<Self> {
+        let contents = fs::read_to_string(path)?;
+        Ok(toml::from_str(&contents)?)
+    }
+
+    fn rule_for(&self, question_number: Option<&str>) -> QuestionRule {
+        question_number
+            .and_then(|number| self.rules.get(number))
+            .cloned()
+            .unwrap_or_default()
+    }
+}
+
+/// The outcome of weighing a set of `CheckResult`s against a
+/// `ScoringConfig`: the weighted score (0.0-1.0, unsupported/Grey checks
+/// excluded) and whether it clears the config's threshold.
+#[derive(Debug)]
+pub struct Verdict {
+    pub score: f64,
+    pub passed: bool,
+}
+
+/// A single question's pass/warn/fail verdict, independent of the
+/// aggregate weighted score, so a per-question rule can flag a check
+/// stricter or looser than its Green/Yellow/Red status alone would imply.
+#[derive(Debug, Clone, PartialEq)]
+pub enum QuestionOutcome {
+    Pass,
+    Warn,
+    Fail,
+}
+
+impl QuestionOutcome {
+    pub fn value(&self) -> &'static str {
+        match self {
+            QuestionOutcome::Pass => "pass",
+            QuestionOutcome::Warn => "warn",
+            QuestionOutcome::Fail => "fail",
+        }
+    }
+}
+
+/// Computes a weighted `Verdict` for `results` against `config`. Green
+/// contributes full credit, Yellow half, Red none, and Grey (unsupported)
+/// checks are excluded entirely, matching `CheckResult::score`.
+pub fn score(results: &[CheckResult], config: &ScoringConfig) -> Verdict {
+    let mut weighted_sum = 0.0;
+    let mut weight_total = 0.0;
+
+    for result in results {
+        if let Some(check_score) = result.score() {
+            let weight = config.rule_for(result.question_number()).weight;
+            weighted_sum += check_score * weight;
+            weight_total += weight;
+        }
+    }
+
+    let score = if weight_total > 0.0 {
+        weighted_sum / weight_total
+    } else {
+        1.0
+    };
+
+    Verdict {
+        score,
+        passed: score >= config.threshold,
+    }
+}
+
+/// Labels each scorable result with an explicit pass/warn/fail verdict per
+/// its question's rule, so a caller can render per-question verdicts
+/// alongside the rolled-up `score()`.
+pub fn per_question_outcomes(
+    results: &[CheckResult],
+    config: &ScoringConfig,
+) -> Vec<(String, QuestionOutcome)> {
+    results
+        .iter()
+        .filter_map(|result| {
+            let check_score = result.score()?;
+            let number = result.question_number()?.to_string();
+            let outcome = config.rule_for(Some(&number)).outcome_for(check_score);
+            Some((number, outcome))
+        })
+        .collect()
+}
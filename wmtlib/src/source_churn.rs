@@ -0,0 +1,181 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::{anyhow, Result};
+use flate2::read::GzDecoder;
+use tar::Archive;
+
+use crate::churn::diff_bytes;
+use crate::HTTPClient;
+
+/// Inserted/deleted byte counts for one file shared between two tarballs.
+#[derive(Debug)]
+pub struct FileChurn {
+    pub path: String,
+    pub inserted_bytes: usize,
+    pub deleted_bytes: usize,
+}
+
+/// The aggregated source churn between two published versions of a crate.
+#[derive(Debug)]
+pub struct ChurnReport {
+    pub files: Vec<FileChurn>,
+    pub inserted_bytes: usize,
+    pub deleted_bytes: usize,
+    pub total_old_bytes: usize,
+}
+
+impl ChurnReport {
+    /// The share of the old source that changed; `0.0` for byte-identical
+    /// tarballs, used to flag likely no-op/metadata-only releases.
+    pub fn churn_ratio(&self) -> f64 {
+        if self.total_old_bytes == 0 {
+            return 0.0;
+        }
+        (self.inserted_bytes + self.deleted_bytes) as f64 / self.total_old_bytes as f64
+    }
+}
+
+/// Downloads and extracts crates.io's `.crate` tarball for `name`@`version`
+/// into a map of source file path to contents.
+async fn fetch_tarball_files(name: &str, version: &str) -> Result<HashMap<String, Vec<u8>>> {
+    let url = format!(
+        "https://crates.io/api/v1/crates/{}/{}/download",
+        name, version
+    );
+    let response = HTTPClient::new()
+        .get(&url)
+        .await
+        .ok_or_else(|| anyhow!("Could not download {}@{}", name, version))?;
+    let bytes = response.bytes().await?;
+
+    let mut files = HashMap::new();
+    let mut archive = Archive::new(GzDecoder::new(&bytes[..]));
+    for entry in archive.entries()? {
+        let mut entry = entry?;
+        if !entry.header().entry_type().is_file() {
+            continue;
+        }
+        let path = entry.path()?.to_string_lossy().to_string();
+        let mut contents = Vec::new();
+        entry.read_to_end(&mut contents)?;
+        files.insert(path, contents);
+    }
+
+    Ok(files)
+}
+
+/// An on-disk extraction of a crate's published tarball, so fallback
+/// lookups that need real files (e.g. [`crate::release_date::ReleaseDate::resolve`]'s
+/// local-checkout tiers) have something to inspect. Not an actual git
+/// checkout — it carries an empty `.git` marker of its own precisely so
+/// `git2::Repository::discover`'s upward directory walk stops right there
+/// instead of silently wandering into whatever unrelated repository might
+/// happen to contain `$TMPDIR` and reporting *that* repo's history as this
+/// checkout's. Removes its temp directory when dropped.
+pub struct TarballCheckout {
+    root: PathBuf,
+    inner: PathBuf,
+}
+
+impl TarballCheckout {
+    pub fn path(&self) -> &Path {
+        &self.inner
+    }
+}
+
+impl Drop for TarballCheckout {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.root);
+    }
+}
+
+/// Downloads and extracts crates.io's `.crate` tarball for `name`@`version`
+/// to a fresh temp directory, for callers that need real files on disk
+/// rather than just their in-memory contents.
+pub async fn checkout_tarball(name: &str, version: &str) -> Result<TarballCheckout> {
+    let url = format!(
+        "https://crates.io/api/v1/crates/{}/{}/download",
+        name, version
+    );
+    let response = HTTPClient::new()
+        .get(&url)
+        .await
+        .ok_or_else(|| anyhow!("Could not download {}@{}", name, version))?;
+    let bytes = response.bytes().await?;
+
+    let unique = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_nanos())
+        .unwrap_or_default();
+    let root = std::env::temp_dir().join(format!("wmt-checkout-{}-{}-{}", name, version, unique));
+    fs::create_dir_all(&root)?;
+
+    let mut archive = Archive::new(GzDecoder::new(&bytes[..]));
+    archive.unpack(&root)?;
+
+    // crates.io tarballs wrap their contents in a `{name}-{version}/` directory.
+    let inner = root.join(format!("{}-{}", name, version));
+    let inner = if inner.is_dir() { inner } else { root.clone() };
+
+    // An empty `.git` directory is not a valid repository, so it makes
+    // `git2::Repository::discover` stop and fail right here rather than
+    // walking past it into some unrelated ancestor repository.
+    fs::create_dir_all(inner.join(".git"))?;
+
+    Ok(TarballCheckout { root, inner })
+}
+
+/// Quantifies how much source actually changed between `old_version` and
+/// `new_version` of `name` by rdiff-diffing every file shared between the
+/// two tarballs; files only present in one are counted as fully
+/// inserted/deleted.
+pub async fn churn_between(name: &str, old_version: &str, new_version: &str) -> Result<ChurnReport> {
+    let old_files = fetch_tarball_files(name, old_version).await?;
+    let new_files = fetch_tarball_files(name, new_version).await?;
+
+    let mut files = Vec::new();
+    let mut inserted_bytes = 0usize;
+    let mut deleted_bytes = 0usize;
+    let mut total_old_bytes = 0usize;
+
+    for (path, old_contents) in &old_files {
+        total_old_bytes += old_contents.len();
+
+        let (inserted, deleted) = match new_files.get(path) {
+            Some(new_contents) => diff_bytes(old_contents, new_contents),
+            None => (0, old_contents.len()),
+        };
+
+        if inserted > 0 || deleted > 0 {
+            files.push(FileChurn {
+                path: path.clone(),
+                inserted_bytes: inserted,
+                deleted_bytes: deleted,
+            });
+        }
+        inserted_bytes += inserted;
+        deleted_bytes += deleted;
+    }
+
+    for (path, new_contents) in &new_files {
+        if !old_files.contains_key(path) {
+            files.push(FileChurn {
+                path: path.clone(),
+                inserted_bytes: new_contents.len(),
+                deleted_bytes: 0,
+            });
+            inserted_bytes += new_contents.len();
+        }
+    }
+
+    Ok(ChurnReport {
+        files,
+        inserted_bytes,
+        deleted_bytes,
+        total_old_bytes,
+    })
+}
@@ -7,13 +7,24 @@ pub struct Version {
 }
 
 impl Version {
+    /// Parses a concrete version or a `Cargo.toml` version requirement
+    /// (`"1.2.3"`, `"^1.28"`, `"~0.8"`, `"=2.0"`, `"1"`, `"*"`) into its
+    /// major/minor/patch components. Any pre-release/build metadata
+    /// (`-rc.1`, `+build5`) is dropped, and a missing minor/patch or an
+    /// unparseable component defaults to `0` rather than panicking.
     pub fn from_version_text(text: &str) -> Self {
-        let semver: Vec<&str> = text.split('.').collect();
+        let without_operator = text.trim().trim_start_matches(['^', '~', '=', '>', '<', ' ']);
+        let core = without_operator
+            .split(['-', '+'])
+            .next()
+            .unwrap_or(without_operator);
+        let mut components = core.split('.');
+
         Version {
             id: text.to_string(),
-            major: semver.get(0).unwrap().parse::<u64>().unwrap(),
-            minor: semver.get(1).unwrap().parse::<u64>().unwrap(),
-            patch: semver.get(2).unwrap().parse::<u64>().unwrap(),
+            major: parse_component(components.next()),
+            minor: parse_component(components.next()),
+            patch: parse_component(components.next()),
         }
     }
 
@@ -25,3 +36,9 @@ impl Version {
         self.minor.ge(&1)
     }
 }
+
+/// Parses a single dot-separated component, defaulting to `0` when it's
+/// missing or not a plain number (e.g. a wildcard `*`).
+fn parse_component(component: Option<&str>) -> u64 {
+    component.and_then(|value| value.parse::<u64>().ok()).unwrap_or(0)
+}
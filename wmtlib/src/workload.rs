@@ -0,0 +1,81 @@
+use std::fs::File;
+use std::io::BufReader;
+
+use serde::{Deserialize, Serialize};
+
+use crate::check::{CheckResult, CrateCheck};
+use crate::log_if_verbose;
+
+/// The schema of a workload manifest: a named batch of repositories to
+/// evaluate, optionally restricted to a subset of question numbers.
+#[derive(Debug, Deserialize)]
+pub struct WorkloadFile {
+    pub name: String,
+    pub repos: Vec<String>,
+    #[serde(default)]
+    pub checks: Option<Vec<String>>,
+}
+
+/// The aggregated result of running a workload's question suite against
+/// every one of its repositories.
+#[derive(Debug, Serialize)]
+pub struct WorkloadReport {
+    pub name: String,
+    pub repos: Vec<RepoReport>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RepoReport {
+    pub repo: String,
+    pub health_percentage: f64,
+    pub results: Vec<CheckResult>,
+}
+
+/// Reads a workload manifest from `path` and runs its question suite
+/// against every listed repository, scoring each one's results into a
+/// `health_percentage` so the repos can be compared at a glance.
+pub fn run_workload(
+    path: &str,
+    verbose: bool,
+    concurrency: Option<usize>,
+) -> anyhow::Result<WorkloadReport> {
+    let file = File::open(path)?;
+    let workload: WorkloadFile = serde_json::from_reader(BufReader::new(file))?;
+
+    let mut repos = Vec::new();
+    for repo in &workload.repos {
+        log_if_verbose(verbose, format!("Running workload checks for {}", repo).as_str());
+        let crate_check = CrateCheck::new(vec![repo.clone()], verbose, concurrency, false, false, None);
+
+        let results: Vec<CheckResult> = match &workload.checks {
+            Some(question_numbers) => question_numbers
+                .iter()
+                .flat_map(|question| crate_check.run_checks(Some(question.clone())))
+                .flatten()
+                .collect(),
+            None => crate_check.run_checks(None).into_iter().flatten().collect(),
+        };
+
+        repos.push(RepoReport {
+            repo: repo.clone(),
+            health_percentage: health_percentage(&results),
+            results,
+        });
+    }
+
+    Ok(WorkloadReport {
+        name: workload.name,
+        repos,
+    })
+}
+
+/// The share of scorable checks (Green/Yellow/Red; unsupported checks are
+/// excluded) that passed, with a Yellow counting as half credit.
+fn health_percentage(results: &[CheckResult]) -> f64 {
+    let scores: Vec<f64> = results.iter().filter_map(CheckResult::score).collect();
+    if scores.is_empty() {
+        return 0.0;
+    }
+
+    (scores.iter().sum::<f64>() / scores.len() as f64) * 100.0
+}